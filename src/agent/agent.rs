@@ -229,6 +229,7 @@ impl Agent {
 
         // Library search.
         registry.register(Box::new(tools::LibrarySearchTool));
+        registry.register(Box::new(tools::LibraryVerifyTool));
 
         // Documentation generation.
         registry.register(Box::new(tools::DocGenTool));
@@ -243,6 +244,7 @@ impl Agent {
         registry.register(Box::new(tools::AgentSpawnTool));
         registry.register(Box::new(tools::AgentMessageTool));
         registry.register(Box::new(tools::AgentRetireTool));
+        registry.register(Box::new(tools::AgentBatchTool));
 
         // Trigger management.
         registry.register(Box::new(tools::TriggerManageTool));
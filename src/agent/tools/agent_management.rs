@@ -1,8 +1,9 @@
 //! Agent management tools: multi-agent orchestration via akhomed workspaces.
 //!
-//! Four tools that let one agent create, list, message, and retire other
-//! agent workspaces. All calls route through akhomed's REST API using `ureq`.
-//! When akhomed is not running, tools return a descriptive error.
+//! Tools that let one agent create, list, message, and retire other agent
+//! workspaces, plus a batch tool for running many such operations in one
+//! call. All calls route through akhomed's REST API using `ureq`. When
+//! akhomed is not running, tools return a descriptive error.
 
 use crate::agent::error::AgentResult;
 use crate::agent::tool::{Tool, ToolInput, ToolOutput, ToolParam, ToolSignature};
@@ -11,11 +12,31 @@ use crate::agent::tool_manifest::{
 };
 use crate::engine::Engine;
 use std::collections::HashSet;
+use std::sync::OnceLock;
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Process-wide pooled HTTP client shared by all agent management tools.
+static HTTP_CLIENT: OnceLock<ureq::Agent> = OnceLock::new();
+
+/// Return the shared pooled `ureq::Agent`, building it on first use.
+///
+/// A single pooled agent reuses keep-alive connections to akhomed across the
+/// many small REST calls these tools make, rather than re-establishing a
+/// connection (and re-running the TLS handshake, when akhomed is served over
+/// HTTPS) on every call. The agent's own default timeout is 10s; call sites
+/// that need a different per-call timeout (e.g. [`AgentListTool`]) override
+/// it with `.timeout(..)` on the individual request.
+fn agent_client() -> &'static ureq::Agent {
+    HTTP_CLIENT.get_or_init(|| {
+        ureq::AgentBuilder::new()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+    })
+}
+
 /// Discover the akhomed base URL, returning an error output if unavailable.
 fn discover_base_url() -> Result<String, ToolOutput> {
     let paths = crate::paths::AkhPaths::resolve().map_err(|e| {
@@ -29,6 +50,125 @@ fn discover_base_url() -> Result<String, ToolOutput> {
     }
 }
 
+/// Create workspace `name` via akhomed, optionally assigning an Ennead role.
+///
+/// Shared by [`AgentSpawnTool`] and [`AgentBatchTool`] so both the single-call
+/// and batch paths create workspaces identically.
+fn spawn_workspace(base_url: &str, name: &str, role: Option<&str>) -> Result<String, String> {
+    let http = agent_client();
+
+    let create_url = format!("{base_url}/workspaces/{name}");
+    match http.post(&create_url).call() {
+        Ok(resp) if resp.status() == 200 => { /* created */ }
+        Ok(resp) => {
+            let body = resp.into_string().unwrap_or_default();
+            return Err(format!("Failed to create workspace \"{name}\": {body}"));
+        }
+        Err(ureq::Error::Status(code, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            return Err(format!("HTTP {code} creating workspace \"{name}\": {body}"));
+        }
+        Err(ureq::Error::Transport(t)) => {
+            return Err(format!("Transport error creating workspace \"{name}\": {t}"));
+        }
+    }
+
+    if let Some(role_name) = role {
+        let assign_url = format!("{base_url}/workspaces/{name}/assign-role");
+        let payload = serde_json::json!({ "role": role_name });
+
+        match http
+            .post(&assign_url)
+            .set("Content-Type", "application/json")
+            .send_string(&payload.to_string())
+        {
+            Ok(_) => {}
+            Err(ureq::Error::Status(code, resp)) => {
+                let body = resp.into_string().unwrap_or_default();
+                return Err(format!(
+                    "Workspace \"{name}\" created but failed to assign role: HTTP {code}: {body}"
+                ));
+            }
+            Err(ureq::Error::Transport(t)) => {
+                return Err(format!(
+                    "Workspace \"{name}\" created but failed to assign role: {t}"
+                ));
+            }
+        }
+    }
+
+    let role_msg = role
+        .map(|r| format!(" with role \"{r}\""))
+        .unwrap_or_default();
+    Ok(format!("Agent workspace \"{name}\" created{role_msg}."))
+}
+
+/// Deliver `message` to `workspace`'s inbox by ingesting triples into its KG.
+///
+/// Shared by [`AgentMessageTool`] and [`AgentBatchTool`].
+fn message_workspace(
+    base_url: &str,
+    workspace: &str,
+    sender: &str,
+    message: &str,
+) -> Result<String, String> {
+    let triples: Vec<(String, String, String, f32)> = vec![
+        (
+            "agent:inbox".into(),
+            "agent:message".into(),
+            message.to_string(),
+            1.0,
+        ),
+        ("agent:inbox".into(), "agent:from".into(), sender.into(), 1.0),
+    ];
+
+    let url = format!("{base_url}/workspaces/{workspace}/ingest");
+    let payload = serde_json::json!({ "triples": triples });
+
+    match agent_client()
+        .post(&url)
+        .set("Content-Type", "application/json")
+        .send_string(&payload.to_string())
+    {
+        Ok(resp) if resp.status() == 200 => Ok(format!(
+            "Message delivered to \"{workspace}\" from \"{sender}\"."
+        )),
+        Ok(resp) => {
+            let body = resp.into_string().unwrap_or_default();
+            Err(format!(
+                "Failed to deliver message to \"{workspace}\": {body}"
+            ))
+        }
+        Err(ureq::Error::Status(code, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            Err(format!(
+                "HTTP {code} delivering message to \"{workspace}\": {body}"
+            ))
+        }
+        Err(ureq::Error::Transport(t)) => Err(format!("Transport error: {t}")),
+    }
+}
+
+/// Delete `workspace` via akhomed.
+///
+/// Shared by [`AgentRetireTool`] and [`AgentBatchTool`].
+fn retire_workspace(base_url: &str, workspace: &str) -> Result<String, String> {
+    let url = format!("{base_url}/workspaces/{workspace}");
+
+    match agent_client().delete(&url).call() {
+        Ok(resp) if resp.status() == 200 => Ok(format!("Agent workspace \"{workspace}\" retired.")),
+        Ok(resp) => {
+            let body = resp.into_string().unwrap_or_default();
+            Err(format!("Failed to retire workspace \"{workspace}\": {body}"))
+        }
+        Err(ureq::Error::Status(code, resp)) => {
+            let body = resp.into_string().unwrap_or_default();
+            Err(format!("HTTP {code} retiring workspace \"{workspace}\": {body}"))
+        }
+        Err(ureq::Error::Transport(t)) => Err(format!("Transport error: {t}")),
+    }
+}
+
 /// Derive the current workspace name from the engine's data_dir path.
 ///
 /// Workspace data dirs follow `…/workspaces/<name>/kg`.  We extract `<name>`
@@ -68,11 +208,14 @@ impl Tool for AgentListTool {
         };
 
         let url = format!("{base_url}/workspaces");
-        let agent = ureq::AgentBuilder::new()
-            .timeout(std::time::Duration::from_secs(5))
-            .build();
 
-        match agent.get(&url).call() {
+        // Listing is expected to be fast; keep its original 5s timeout
+        // rather than inheriting the pooled client's 10s default.
+        match agent_client()
+            .get(&url)
+            .timeout(std::time::Duration::from_secs(5))
+            .call()
+        {
             Ok(resp) => match resp.into_string() {
                 Ok(body) => Ok(ToolOutput::ok(format!("Agent workspaces:\n{body}"))),
                 Err(e) => Ok(ToolOutput::err(format!("Failed to read response: {e}"))),
@@ -145,62 +288,10 @@ impl Tool for AgentSpawnTool {
             Err(out) => return Ok(out),
         };
 
-        let http = ureq::AgentBuilder::new()
-            .timeout(std::time::Duration::from_secs(10))
-            .build();
-
-        // 1. Create the workspace.
-        let create_url = format!("{base_url}/workspaces/{name}");
-        match http.post(&create_url).call() {
-            Ok(resp) if resp.status() == 200 => { /* created */ }
-            Ok(resp) => {
-                let body = resp.into_string().unwrap_or_default();
-                return Ok(ToolOutput::err(format!(
-                    "Failed to create workspace \"{name}\": {body}"
-                )));
-            }
-            Err(ureq::Error::Status(code, resp)) => {
-                let body = resp.into_string().unwrap_or_default();
-                return Ok(ToolOutput::err(format!(
-                    "HTTP {code} creating workspace \"{name}\": {body}"
-                )));
-            }
-            Err(ureq::Error::Transport(t)) => {
-                return Ok(ToolOutput::err(format!("Transport error: {t}")));
-            }
+        match spawn_workspace(&base_url, name, role) {
+            Ok(msg) => Ok(ToolOutput::ok(msg)),
+            Err(msg) => Ok(ToolOutput::err(msg)),
         }
-
-        // 2. Assign role via the write-once assign-role endpoint if provided.
-        if let Some(role_name) = role {
-            let assign_url = format!("{base_url}/workspaces/{name}/assign-role");
-            let payload = serde_json::json!({ "role": role_name });
-
-            match http
-                .post(&assign_url)
-                .set("Content-Type", "application/json")
-                .send_string(&payload.to_string())
-            {
-                Ok(_) => {}
-                Err(ureq::Error::Status(code, resp)) => {
-                    let body = resp.into_string().unwrap_or_default();
-                    return Ok(ToolOutput::err(format!(
-                        "Workspace \"{name}\" created but failed to assign role: HTTP {code}: {body}"
-                    )));
-                }
-                Err(ureq::Error::Transport(t)) => {
-                    return Ok(ToolOutput::err(format!(
-                        "Workspace \"{name}\" created but failed to assign role: {t}"
-                    )));
-                }
-            }
-        }
-
-        let role_msg = role
-            .map(|r| format!(" with role \"{r}\""))
-            .unwrap_or_default();
-        Ok(ToolOutput::ok(format!(
-            "Agent workspace \"{name}\" created{role_msg}."
-        )))
     }
 
     fn manifest(&self) -> ToolManifest {
@@ -270,51 +361,9 @@ impl Tool for AgentMessageTool {
 
         let sender = current_workspace_name(engine);
 
-        let triples: Vec<(String, String, String, f32)> = vec![
-            (
-                "agent:inbox".into(),
-                "agent:message".into(),
-                message.to_string(),
-                1.0,
-            ),
-            (
-                "agent:inbox".into(),
-                "agent:from".into(),
-                sender.clone(),
-                1.0,
-            ),
-        ];
-
-        let url = format!("{base_url}/workspaces/{workspace}/ingest");
-        let payload = serde_json::json!({ "triples": triples });
-
-        let http = ureq::AgentBuilder::new()
-            .timeout(std::time::Duration::from_secs(10))
-            .build();
-
-        match http
-            .post(&url)
-            .set("Content-Type", "application/json")
-            .send_string(&payload.to_string())
-        {
-            Ok(resp) if resp.status() == 200 => Ok(ToolOutput::ok(format!(
-                "Message delivered to \"{workspace}\" from \"{sender}\"."
-            ))),
-            Ok(resp) => {
-                let body = resp.into_string().unwrap_or_default();
-                Ok(ToolOutput::err(format!(
-                    "Failed to deliver message to \"{workspace}\": {body}"
-                )))
-            }
-            Err(ureq::Error::Status(code, resp)) => {
-                let body = resp.into_string().unwrap_or_default();
-                Ok(ToolOutput::err(format!(
-                    "HTTP {code} delivering message to \"{workspace}\": {body}"
-                )))
-            }
-            Err(ureq::Error::Transport(t)) => {
-                Ok(ToolOutput::err(format!("Transport error: {t}")))
-            }
+        match message_workspace(&base_url, workspace, &sender, message) {
+            Ok(msg) => Ok(ToolOutput::ok(msg)),
+            Err(msg) => Ok(ToolOutput::err(msg)),
         }
     }
 
@@ -372,30 +421,9 @@ impl Tool for AgentRetireTool {
             Err(out) => return Ok(out),
         };
 
-        let url = format!("{base_url}/workspaces/{workspace}");
-        let http = ureq::AgentBuilder::new()
-            .timeout(std::time::Duration::from_secs(10))
-            .build();
-
-        match http.delete(&url).call() {
-            Ok(resp) if resp.status() == 200 => Ok(ToolOutput::ok(format!(
-                "Agent workspace \"{workspace}\" retired."
-            ))),
-            Ok(resp) => {
-                let body = resp.into_string().unwrap_or_default();
-                Ok(ToolOutput::err(format!(
-                    "Failed to retire workspace \"{workspace}\": {body}"
-                )))
-            }
-            Err(ureq::Error::Status(code, resp)) => {
-                let body = resp.into_string().unwrap_or_default();
-                Ok(ToolOutput::err(format!(
-                    "HTTP {code} retiring workspace \"{workspace}\": {body}"
-                )))
-            }
-            Err(ureq::Error::Transport(t)) => {
-                Ok(ToolOutput::err(format!("Transport error: {t}")))
-            }
+        match retire_workspace(&base_url, workspace) {
+            Ok(msg) => Ok(ToolOutput::ok(msg)),
+            Err(msg) => Ok(ToolOutput::err(msg)),
         }
     }
 
@@ -422,3 +450,169 @@ impl Tool for AgentRetireTool {
         }
     }
 }
+
+// ===========================================================================
+// AgentBatchTool
+// ===========================================================================
+
+/// One operation within an `agent_batch` call, keyed by `"op"` in the JSON
+/// passed to the `operations` parameter.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum BatchOp {
+    /// Create a workspace, optionally assigning an Ennead role.
+    Spawn {
+        workspace: String,
+        role: Option<String>,
+    },
+    /// Deliver a message to a workspace's inbox.
+    Message { workspace: String, message: String },
+    /// Delete a workspace.
+    Retire { workspace: String },
+}
+
+impl BatchOp {
+    fn workspace(&self) -> &str {
+        match self {
+            BatchOp::Spawn { workspace, .. } => workspace,
+            BatchOp::Message { workspace, .. } => workspace,
+            BatchOp::Retire { workspace } => workspace,
+        }
+    }
+
+    fn op_name(&self) -> &'static str {
+        match self {
+            BatchOp::Spawn { .. } => "spawn",
+            BatchOp::Message { .. } => "message",
+            BatchOp::Retire { .. } => "retire",
+        }
+    }
+}
+
+/// Per-item result of one `agent_batch` operation.
+#[derive(Debug, Clone, serde::Serialize)]
+struct BatchItemResult {
+    op: String,
+    workspace: String,
+    success: bool,
+    detail: String,
+}
+
+/// Run a list of agent-management operations against akhomed in one call,
+/// reporting which succeeded and which failed rather than aborting on the
+/// first error.
+///
+/// Modeled on a batch API's item-level success/error response: spawning an
+/// Ennead of roles or broadcasting a message to many workspaces becomes one
+/// tool invocation whose result set shows exactly which items need retrying.
+pub struct AgentBatchTool;
+
+impl Tool for AgentBatchTool {
+    fn signature(&self) -> ToolSignature {
+        ToolSignature {
+            name: "agent_batch".into(),
+            description: "Run multiple agent management operations (spawn, message, retire) \
+                          against akhomed in one call, reporting per-item success or failure."
+                .into(),
+            parameters: vec![ToolParam {
+                name: "operations".into(),
+                description: "JSON array of operations, e.g. \
+                    `[{\"op\":\"spawn\",\"workspace\":\"scout\",\"role\":\"Investigator\"},\
+                    {\"op\":\"message\",\"workspace\":\"scout\",\"message\":\"begin\"}]`."
+                    .into(),
+                required: true,
+            }],
+        }
+    }
+
+    fn execute(&self, engine: &Engine, input: ToolInput) -> AgentResult<ToolOutput> {
+        let raw_ops = input.require("operations", "agent_batch")?;
+        let ops: Vec<BatchOp> = match serde_json::from_str(raw_ops) {
+            Ok(ops) => ops,
+            Err(e) => {
+                return Ok(ToolOutput::err(format!(
+                    "Invalid operations JSON: {e}"
+                )));
+            }
+        };
+
+        if ops.is_empty() {
+            return Ok(ToolOutput::err("operations must be a non-empty array."));
+        }
+
+        let base_url = match discover_base_url() {
+            Ok(u) => u,
+            Err(out) => return Ok(out),
+        };
+
+        let sender = current_workspace_name(engine);
+
+        let results: Vec<BatchItemResult> = ops
+            .iter()
+            .map(|op| {
+                let outcome = match op {
+                    BatchOp::Spawn { workspace, role } => {
+                        spawn_workspace(&base_url, workspace, role.as_deref())
+                    }
+                    BatchOp::Message { workspace, message } => {
+                        message_workspace(&base_url, workspace, &sender, message)
+                    }
+                    BatchOp::Retire { workspace } => retire_workspace(&base_url, workspace),
+                };
+                match outcome {
+                    Ok(detail) => BatchItemResult {
+                        op: op.op_name().into(),
+                        workspace: op.workspace().into(),
+                        success: true,
+                        detail,
+                    },
+                    Err(detail) => BatchItemResult {
+                        op: op.op_name().into(),
+                        workspace: op.workspace().into(),
+                        success: false,
+                        detail,
+                    },
+                }
+            })
+            .collect();
+
+        let succeeded = results.iter().filter(|r| r.success).count();
+        let summary = format!(
+            "{succeeded}/{} operations succeeded:\n{}",
+            results.len(),
+            serde_json::to_string_pretty(&results).unwrap_or_default()
+        );
+
+        if succeeded == results.len() {
+            Ok(ToolOutput::ok(summary))
+        } else {
+            Ok(ToolOutput::err(summary))
+        }
+    }
+
+    fn manifest(&self) -> ToolManifest {
+        ToolManifest {
+            name: "agent_batch".into(),
+            description: "Runs a batch of spawn/message/retire operations via akhomed, \
+                          reporting per-item results."
+                .into(),
+            parameters: vec![ToolParamSchema::required(
+                "operations",
+                "JSON array of {op, workspace, ...} operations.",
+            )],
+            danger: DangerInfo {
+                level: DangerLevel::Dangerous,
+                capabilities: HashSet::from([Capability::Network, Capability::WriteKg]),
+                description: "Executes multiple workspace create/message/delete operations."
+                    .into(),
+                shadow_triggers: vec![
+                    "batch".into(),
+                    "bulk".into(),
+                    "ennead".into(),
+                    "broadcast".into(),
+                ],
+            },
+            source: ToolSource::Native,
+        }
+    }
+}
@@ -14,7 +14,7 @@ use crate::agent::tool_manifest::{
 };
 use crate::engine::Engine;
 use crate::library::catalog::LibraryCatalog;
-use crate::library::ingest::{IngestConfig, ingest_file, ingest_url};
+use crate::library::ingest::{IngestConfig, IngestOutcome, ingest_file_auto, ingest_url_auto};
 use crate::paths::AkhPaths;
 
 /// Tool for ingesting documents (files, URLs) into the shared content library.
@@ -25,8 +25,10 @@ impl Tool for ContentIngestTool {
         ToolSignature {
             name: "content_ingest".into(),
             description: "Ingest a document (file or URL) into the shared content library. \
-                          Parses HTML, PDF, EPUB, or plain text. Extracts triples and \
-                          creates VSA embeddings for semantic search."
+                          Parses HTML, PDF, EPUB, or plain text, or transparently unpacks a \
+                          zip/tar/tar.gz/tar.zst/tar.bz2 archive and ingests every document \
+                          inside it. Extracts triples and creates VSA embeddings for semantic \
+                          search."
                 .into(),
             parameters: vec![
                 ToolParam {
@@ -79,24 +81,37 @@ impl Tool for ContentIngestTool {
         };
 
         let result = if source.starts_with("http://") || source.starts_with("https://") {
-            ingest_url(engine, &mut catalog, source, config)
+            ingest_url_auto(engine, &mut catalog, source, config)
         } else {
             let path = PathBuf::from(source);
-            ingest_file(engine, &mut catalog, &path, config)
+            ingest_file_auto(engine, &mut catalog, &path, config)
         };
 
         match result {
-            Ok(res) => {
+            Ok(IngestOutcome::Document(res)) => {
                 let msg = format!(
-                    "Ingested \"{}\" (id={}, {} chunks, {} triples, format={}).",
+                    "Ingested \"{}\" (id={}, {} chunks, {} deduped, {} triples, format={}).",
                     res.record.title,
                     res.record.id,
                     res.chunk_count,
+                    res.chunks_deduped,
                     res.triple_count,
                     res.record.format,
                 );
                 Ok(ToolOutput::ok_with_symbols(msg, vec![res.document_symbol]))
             }
+            Ok(IngestOutcome::Archive(res)) => {
+                let msg = format!(
+                    "Ingested archive \"{}\" ({} documents, {} skipped, {} failed).",
+                    source,
+                    res.ingested.len(),
+                    res.skipped,
+                    res.failed,
+                );
+                let mut symbols: Vec<_> = res.ingested.iter().map(|r| r.document_symbol).collect();
+                symbols.push(res.collection_symbol);
+                Ok(ToolOutput::ok_with_symbols(msg, symbols))
+            }
             Err(e) => Ok(ToolOutput::err(format!("Ingestion failed: {e}"))),
         }
     }
@@ -1,34 +1,306 @@
-//! KG query tool: query triples from/to a symbol.
+//! KG query tool: query triples from/to a symbol, or evaluate a small
+//! conjunctive pattern query across several triples at once.
+//!
+//! A pattern query is a space-separated list of parenthesized triple
+//! patterns, e.g. `(?x is-a Person) (?x biologicalMother ?m)`. Each term is
+//! either a `?var` placeholder or a symbol label/ID. Patterns are joined by
+//! unifying shared variables: at each step the remaining pattern with the
+//! most already-known slots is evaluated next, narrowing the candidate set
+//! as fast as possible, until every pattern has been applied.
+
+use std::collections::{HashMap, HashSet};
 
 use crate::agent::error::AgentResult;
 use crate::agent::tool::{Tool, ToolInput, ToolOutput, ToolParam, ToolSignature};
+use crate::agent::tool_manifest::{
+    Capability, DangerInfo, DangerLevel, ToolManifest, ToolParamSchema, ToolSource,
+};
 use crate::engine::Engine;
+use crate::graph::Triple;
+use crate::symbol::SymbolId;
+
+/// One slot of a triple pattern: a `?var` placeholder, or a symbol already
+/// resolved at parse time.
+#[derive(Debug, Clone)]
+enum PatternSlot {
+    Var(String),
+    Const(SymbolId),
+}
+
+/// A single `(subject predicate object)` pattern from a pattern query.
+#[derive(Debug, Clone)]
+struct TriplePattern {
+    subject: PatternSlot,
+    predicate: PatternSlot,
+    object: PatternSlot,
+}
+
+/// Variable name -> the symbol it's bound to within one candidate solution.
+type Bindings = HashMap<String, SymbolId>;
 
 /// Query triples from/to a symbol in the knowledge graph.
 pub struct KgQueryTool;
 
+impl KgQueryTool {
+    /// Split `query` into its parenthesized triple patterns and resolve
+    /// every non-variable term to a symbol.
+    fn parse_patterns(engine: &Engine, query: &str) -> Result<Vec<TriplePattern>, String> {
+        let mut patterns = Vec::new();
+        let mut depth = 0usize;
+        let mut start = None;
+
+        for (i, ch) in query.char_indices() {
+            match ch {
+                '(' => {
+                    if depth == 0 {
+                        start = Some(i + 1);
+                    }
+                    depth += 1;
+                }
+                ')' => {
+                    if depth == 0 {
+                        return Err("unbalanced parentheses in pattern query".into());
+                    }
+                    depth -= 1;
+                    if depth == 0 {
+                        let body = &query[start.take().unwrap()..i];
+                        patterns.push(Self::parse_one(engine, body)?);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if depth != 0 {
+            return Err("unbalanced parentheses in pattern query".into());
+        }
+        if patterns.is_empty() {
+            return Err("no triple patterns found; expected e.g. (?x is-a Person)".into());
+        }
+        Ok(patterns)
+    }
+
+    fn parse_one(engine: &Engine, body: &str) -> Result<TriplePattern, String> {
+        let tokens: Vec<&str> = body.split_whitespace().collect();
+        let [s, p, o] = tokens.as_slice() else {
+            return Err(format!(
+                "expected exactly 3 terms in pattern \"({body})\", got {}",
+                tokens.len()
+            ));
+        };
+        Ok(TriplePattern {
+            subject: Self::parse_slot(engine, s)?,
+            predicate: Self::parse_slot(engine, p)?,
+            object: Self::parse_slot(engine, o)?,
+        })
+    }
+
+    fn parse_slot(engine: &Engine, token: &str) -> Result<PatternSlot, String> {
+        if let Some(name) = token.strip_prefix('?') {
+            Ok(PatternSlot::Var(name.to_string()))
+        } else {
+            engine
+                .resolve_symbol(token)
+                .map(PatternSlot::Const)
+                .map_err(|e| format!("unknown symbol \"{token}\": {e}"))
+        }
+    }
+
+    /// How many of a pattern's slots are already known (constant, or a
+    /// variable already bound) — used to pick the most selective remaining
+    /// pattern at each join step.
+    fn known_slots(pattern: &TriplePattern, bindings: &Bindings) -> usize {
+        [&pattern.subject, &pattern.predicate, &pattern.object]
+            .into_iter()
+            .filter(|slot| match slot {
+                PatternSlot::Const(_) => true,
+                PatternSlot::Var(name) => bindings.contains_key(name),
+            })
+            .count()
+    }
+
+    fn resolve_slot(slot: &PatternSlot, bindings: &Bindings) -> Option<SymbolId> {
+        match slot {
+            PatternSlot::Const(id) => Some(*id),
+            PatternSlot::Var(name) => bindings.get(name).copied(),
+        }
+    }
+
+    /// Extend every binding in `bindings` by matching `pattern` against the
+    /// knowledge graph, keeping only extensions that unify consistently
+    /// with variables already bound.
+    fn join(engine: &Engine, pattern: &TriplePattern, bindings: Vec<Bindings>) -> Vec<Bindings> {
+        let mut out = Vec::new();
+
+        for binding in bindings {
+            let subject = Self::resolve_slot(&pattern.subject, &binding);
+            let object = Self::resolve_slot(&pattern.object, &binding);
+            let predicate = Self::resolve_slot(&pattern.predicate, &binding);
+
+            let candidates: Vec<Triple> = match (subject, object) {
+                (Some(s), _) => engine.triples_from(s),
+                (None, Some(o)) => engine.triples_to(o),
+                (None, None) => engine.all_triples(),
+            };
+
+            for t in candidates {
+                if subject.is_some_and(|s| s != t.subject)
+                    || object.is_some_and(|o| o != t.object)
+                    || predicate.is_some_and(|p| p != t.predicate)
+                {
+                    continue;
+                }
+
+                let mut extended = binding.clone();
+                let mut consistent = true;
+                for (slot, value) in [
+                    (&pattern.subject, t.subject),
+                    (&pattern.predicate, t.predicate),
+                    (&pattern.object, t.object),
+                ] {
+                    if let PatternSlot::Var(name) = slot {
+                        match extended.get(name) {
+                            Some(existing) if *existing != value => {
+                                consistent = false;
+                                break;
+                            }
+                            _ => {
+                                extended.insert(name.clone(), value);
+                            }
+                        }
+                    }
+                }
+                if consistent {
+                    out.push(extended);
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Evaluate a conjunctive pattern query: seed from the most selective
+    /// pattern, then repeatedly join the remaining patterns in the order
+    /// that narrows the candidate bindings fastest.
+    fn eval_patterns(engine: &Engine, mut patterns: Vec<TriplePattern>) -> Vec<Bindings> {
+        let mut bindings = vec![Bindings::new()];
+
+        while !patterns.is_empty() && !bindings.is_empty() {
+            let next = patterns
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, p)| Self::known_slots(p, &bindings[0]))
+                .map(|(i, _)| i)
+                .expect("patterns is non-empty");
+            let pattern = patterns.remove(next);
+            bindings = Self::join(engine, &pattern, bindings);
+        }
+
+        bindings
+    }
+
+    /// Run a pattern query end to end and render it as tool output.
+    fn execute_pattern_query(engine: &Engine, pattern_str: &str) -> ToolOutput {
+        let patterns = match Self::parse_patterns(engine, pattern_str) {
+            Ok(p) => p,
+            Err(e) => return ToolOutput::err(format!("Cannot parse pattern query: {e}")),
+        };
+
+        let mut var_order = Vec::new();
+        let mut seen = HashSet::new();
+        for p in &patterns {
+            for slot in [&p.subject, &p.predicate, &p.object] {
+                if let PatternSlot::Var(name) = slot {
+                    if seen.insert(name.clone()) {
+                        var_order.push(name.clone());
+                    }
+                }
+            }
+        }
+
+        let bindings = Self::eval_patterns(engine, patterns);
+
+        if bindings.is_empty() {
+            return ToolOutput::ok("No bindings satisfy the pattern query.");
+        }
+
+        let mut lines = Vec::with_capacity(bindings.len());
+        let mut symbols = Vec::new();
+        for binding in &bindings {
+            let row = var_order
+                .iter()
+                .map(|name| {
+                    let id = binding[name];
+                    symbols.push(id);
+                    format!("?{} = \"{}\"", name, engine.resolve_label(id))
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(row);
+        }
+
+        let result = format!(
+            "Found {} binding(s) for {} variable(s):\n{}",
+            bindings.len(),
+            var_order.len(),
+            lines.join("\n")
+        );
+        ToolOutput::ok_with_symbols(result, symbols)
+    }
+}
+
 impl Tool for KgQueryTool {
     fn signature(&self) -> ToolSignature {
         ToolSignature {
             name: "kg_query".into(),
-            description: "Query triples from/to a symbol in the knowledge graph.".into(),
+            description: "Query triples from/to a symbol in the knowledge graph, or evaluate a \
+                          conjunctive pattern query such as \"(?x is-a Person) (?x \
+                          biologicalMother ?m)\"."
+                .into(),
             parameters: vec![
                 ToolParam {
                     name: "symbol".into(),
-                    description: "Symbol name or ID to query.".into(),
-                    required: true,
+                    description: "Symbol name or ID to query. Required unless \"pattern\" is \
+                                  given instead."
+                        .into(),
+                    required: false,
                 },
                 ToolParam {
                     name: "direction".into(),
                     description: "Direction: 'from', 'to', or 'both' (default: both).".into(),
                     required: false,
                 },
+                ToolParam {
+                    name: "pattern".into(),
+                    description: "A conjunctive query of parenthesized triple patterns with \
+                                  `?var` placeholders, e.g. \"(?x is-a Person) (?x \
+                                  biologicalMother ?m)\". When given, this runs instead of the \
+                                  single-symbol query and returns the variable bindings that \
+                                  satisfy every pattern."
+                        .into(),
+                    required: false,
+                },
             ],
         }
     }
 
     fn execute(&self, engine: &Engine, input: ToolInput) -> AgentResult<ToolOutput> {
-        let symbol_str = input.require("symbol", "kg_query")?;
+        let symbol_str = match (input.get("symbol"), input.get("pattern")) {
+            (Some(_), Some(_)) => {
+                return Ok(ToolOutput::err(
+                    "Provide either \"symbol\" or \"pattern\", not both.",
+                ));
+            }
+            (None, Some(pattern_str)) => {
+                return Ok(Self::execute_pattern_query(engine, pattern_str));
+            }
+            (Some(symbol_str), None) => symbol_str,
+            (None, None) => {
+                return Ok(ToolOutput::err(
+                    "Missing required parameter: provide either \"symbol\" or \"pattern\".",
+                ));
+            }
+        };
         let direction = input.get("direction").unwrap_or("both");
 
         let symbol_id = engine
@@ -83,4 +355,32 @@ impl Tool for KgQueryTool {
             Ok(ToolOutput::ok_with_symbols(result, symbols))
         }
     }
+
+    fn manifest(&self) -> ToolManifest {
+        ToolManifest {
+            name: "kg_query".into(),
+            description: "Query triples from/to a symbol, or evaluate a multi-pattern join query \
+                          over the knowledge graph."
+                .into(),
+            parameters: vec![
+                ToolParamSchema::optional(
+                    "symbol",
+                    "Symbol name or ID to query. Required unless \"pattern\" is given instead.",
+                ),
+                ToolParamSchema::optional("direction", "Direction: 'from', 'to', or 'both'."),
+                ToolParamSchema::optional(
+                    "pattern",
+                    "Conjunctive query of `?var` triple patterns, e.g. \"(?x is-a Person)\".",
+                ),
+            ],
+            danger: DangerInfo {
+                level: DangerLevel::Safe,
+                capabilities: HashSet::from([Capability::ReadKg]),
+                description: "Reads triples from the knowledge graph; read-only, no side effects."
+                    .into(),
+                shadow_triggers: vec![],
+            },
+            source: ToolSource::Native,
+        }
+    }
 }
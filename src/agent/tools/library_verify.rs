@@ -0,0 +1,315 @@
+//! Library verify tool: check the content library's integrity offline.
+//!
+//! Recomputes the CRC32/SHA-256 digests recorded at ingest time for each
+//! document's raw source bytes and for each chunk's canonicalized text
+//! (re-derived by re-parsing the resolved source), and confirms every
+//! chunk's VSA embedding is still present in item memory. Reports
+//! mismatches without requiring a re-download of the source.
+
+use std::collections::HashSet;
+
+use crate::agent::error::AgentResult;
+use crate::agent::tool::{Tool, ToolInput, ToolOutput, ToolParam, ToolSignature};
+use crate::agent::tool_manifest::{
+    Capability, DangerInfo, DangerLevel, ToolManifest, ToolParamSchema, ToolSource,
+};
+use crate::engine::Engine;
+use crate::library::archive;
+use crate::library::catalog::LibraryCatalog;
+use crate::library::chunker::{ChunkConfig, normalize_chunks};
+use crate::library::ingest::{IngestConfig, ingest_file, normalize_chunk_text};
+use crate::library::model::DocumentSource;
+use crate::library::parser;
+use crate::paths::AkhPaths;
+use crate::symbol::SymbolId;
+use sha2::{Digest, Sha256};
+
+/// Why [`resolve_source_bytes`] couldn't return the bytes a document was
+/// ingested from.
+enum SourceCheckError {
+    /// The source simply isn't backed by anything rereadable offline (a
+    /// URL or inline text) — not evidence of corruption.
+    Unverifiable(String),
+    /// The source claims to be locally rereadable but isn't anymore, or no
+    /// longer matches what ingestion recorded — an actual problem.
+    Problem(String),
+}
+
+/// Recover the exact raw bytes a document was ingested from, recursing into
+/// the parent archive for [`DocumentSource::ArchiveEntry`] so an archived
+/// document's integrity can be rechecked without a fake on-disk path.
+fn resolve_source_bytes(source: &DocumentSource) -> Result<Vec<u8>, SourceCheckError> {
+    match source {
+        DocumentSource::File(path) => std::fs::read(path)
+            .map_err(|e| SourceCheckError::Problem(format!("source file unreadable: {e}"))),
+        DocumentSource::Url(_) | DocumentSource::Inline => Err(SourceCheckError::Unverifiable(
+            "source is not a local file; raw-byte digest can't be rechecked offline".into(),
+        )),
+        DocumentSource::ArchiveEntry { archive: parent, entry } => {
+            let archive_bytes = resolve_source_bytes(parent)?;
+            let entries = archive::extract_entries(&archive_bytes).map_err(|e| {
+                SourceCheckError::Problem(format!("failed to re-extract archive: {e}"))
+            })?;
+            entries
+                .into_iter()
+                .find(|(name, _)| name == entry)
+                .map(|(_, bytes)| bytes)
+                .ok_or_else(|| {
+                    SourceCheckError::Problem(format!(
+                        "entry \"{entry}\" no longer present in archive"
+                    ))
+                })
+        }
+    }
+}
+
+/// Walk the library catalog and verify each document's integrity.
+pub struct LibraryVerifyTool;
+
+impl Tool for LibraryVerifyTool {
+    fn signature(&self) -> ToolSignature {
+        ToolSignature {
+            name: "library_verify".into(),
+            description: "Verify the content library's integrity by recomputing source \
+                          digests and checking that chunk embeddings are still present. \
+                          Optionally repairs corrupted documents by re-ingesting them."
+                .into(),
+            parameters: vec![
+                ToolParam {
+                    name: "document".into(),
+                    description: "Limit verification to a single document slug (optional)."
+                        .into(),
+                    required: false,
+                },
+                ToolParam {
+                    name: "repair".into(),
+                    description: "If \"true\", re-ingest documents found to have problems, \
+                                  when their source is a local file (optional, default \
+                                  false)."
+                        .into(),
+                    required: false,
+                },
+            ],
+        }
+    }
+
+    fn execute(&self, engine: &Engine, input: ToolInput) -> AgentResult<ToolOutput> {
+        let document_filter = input.get("document").map(|s| s.to_string());
+        let repair = input.get("repair").map(|s| s == "true").unwrap_or(false);
+
+        let library_dir = match AkhPaths::resolve() {
+            Ok(paths) => paths.library_dir(),
+            Err(_) => {
+                return Ok(ToolOutput::err(
+                    "Cannot resolve library directory. Set HOME environment variable.",
+                ));
+            }
+        };
+
+        let mut catalog = match LibraryCatalog::open(&library_dir) {
+            Ok(c) => c,
+            Err(e) => return Ok(ToolOutput::err(format!("Cannot open catalog: {e}"))),
+        };
+
+        let ids: Vec<String> = catalog
+            .list()
+            .iter()
+            .filter(|r| document_filter.as_deref().map_or(true, |id| r.id == id))
+            .map(|r| r.id.clone())
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(ToolOutput::ok("No documents matched; nothing to verify."));
+        }
+
+        let mut lines = Vec::new();
+        let mut symbols = Vec::new();
+        let mut clean = 0usize;
+        let mut corrupted = 0usize;
+        let mut unverifiable = 0usize;
+        let mut repaired = 0usize;
+
+        for id in ids {
+            let Some(record) = catalog.get(&id) else {
+                continue;
+            };
+
+            let mut problems = Vec::new();
+            let mut source_note = None;
+            let mut source_bytes = None;
+
+            match resolve_source_bytes(&record.source) {
+                Ok(data) => {
+                    if crc32fast::hash(&data) != record.source_crc32
+                        || format!("{:x}", Sha256::digest(&data)) != record.source_sha256
+                    {
+                        problems.push("source bytes do not match ingest-time digest".into());
+                    }
+                    source_bytes = Some(data);
+                }
+                // Not a problem by itself — just means the raw-byte digest
+                // can't be rechecked offline, not that anything is wrong.
+                Err(SourceCheckError::Unverifiable(reason)) => source_note = Some(reason),
+                Err(SourceCheckError::Problem(reason)) => problems.push(reason),
+            }
+
+            // Re-parse the resolved source through the same chunking path used
+            // at ingest time, so chunk text corruption is detectable and not
+            // just embedding presence. Best-effort: the original ChunkConfig
+            // isn't persisted, so this assumes the default was used; when the
+            // source couldn't be resolved at all, chunk text can't be rechecked
+            // either and this silently yields `None`.
+            let reparsed_chunks = source_bytes.as_deref().and_then(|data| {
+                parser::parser_for(record.format)
+                    .ok()
+                    .and_then(|parser| parser.parse(data).ok())
+                    .map(|parsed| normalize_chunks(&parsed.raw_chunks, &ChunkConfig::default()))
+            });
+
+            for (i, chunk) in record.chunk_records.iter().enumerate() {
+                let Some(symbol) = SymbolId::new(chunk.symbol) else {
+                    problems.push(format!("chunk has invalid symbol id {}", chunk.symbol));
+                    continue;
+                };
+                if !engine.item_memory().contains(symbol) {
+                    problems.push(format!("chunk embedding missing for symbol {}", chunk.symbol));
+                }
+
+                if let Some(chunks) = &reparsed_chunks {
+                    match chunks.get(i) {
+                        Some(reparsed) => {
+                            let normalized = normalize_chunk_text(&reparsed.text);
+                            if crc32fast::hash(normalized.as_bytes()) != chunk.crc32
+                                || format!("{:x}", Sha256::digest(normalized.as_bytes()))
+                                    != chunk.sha256
+                            {
+                                problems.push(format!(
+                                    "chunk {i} text does not match ingest-time digest"
+                                ));
+                            }
+                        }
+                        None => problems.push(format!(
+                            "chunk {i} missing from re-parsed source (chunk count changed \
+                             since ingest)"
+                        )),
+                    }
+                }
+            }
+
+            if let Some(sym) = SymbolId::new(record.document_symbol) {
+                symbols.push(sym);
+            }
+
+            if problems.is_empty() {
+                match source_note {
+                    Some(note) => {
+                        unverifiable += 1;
+                        lines.push(format!("\"{id}\": {note} (no other problems detected)"));
+                    }
+                    None => clean += 1,
+                }
+                continue;
+            }
+
+            corrupted += 1;
+            let source_integrity_failed = problems
+                .iter()
+                .any(|p| p.starts_with("source bytes") || p.starts_with("source file unreadable"));
+            let mut status = format!("\"{}\": {}", id, problems.join("; "));
+            if let Some(note) = source_note {
+                status.push_str(&format!("; {note}"));
+            }
+
+            if repair {
+                if let DocumentSource::File(path) = &record.source {
+                    let path = path.clone();
+                    match catalog.remove(&id) {
+                        Ok(removed) => match ingest_file(
+                            engine,
+                            &mut catalog,
+                            std::path::Path::new(&path),
+                            IngestConfig {
+                                dedup: false,
+                                ..Default::default()
+                            },
+                        ) {
+                            Ok(_) => {
+                                repaired += 1;
+                                if source_integrity_failed {
+                                    status.push_str(
+                                        " -> re-ingested the current on-disk bytes (the \
+                                         original source couldn't be verified, so this may not \
+                                         match what was originally recorded)",
+                                    );
+                                } else {
+                                    status.push_str(" -> repaired by re-ingesting");
+                                }
+                            }
+                            Err(e) => {
+                                // Re-ingest failed; restore the record we removed so a
+                                // failed repair attempt doesn't leave the catalog without
+                                // any record of this document at all.
+                                let _ = catalog.add(removed);
+                                status.push_str(&format!(" -> repair failed: {e}"));
+                            }
+                        },
+                        Err(e) => status.push_str(&format!(" -> repair failed: {e}")),
+                    }
+                } else {
+                    status.push_str(" -> cannot repair a non-file source automatically");
+                }
+            }
+
+            lines.push(status);
+        }
+
+        let summary = format!(
+            "Verified {} document(s): {clean} clean, {corrupted} with problems, \
+             {unverifiable} unverifiable{}.",
+            clean + corrupted + unverifiable,
+            if repair {
+                format!(", {repaired} repaired")
+            } else {
+                String::new()
+            }
+        );
+
+        let result = if lines.is_empty() {
+            summary
+        } else {
+            format!("{summary}\n{}", lines.join("\n"))
+        };
+
+        Ok(ToolOutput::ok_with_symbols(result, symbols))
+    }
+
+    fn manifest(&self) -> ToolManifest {
+        ToolManifest {
+            name: "library_verify".into(),
+            description: "Recompute digests for library documents and report corruption or \
+                          missing chunk embeddings."
+                .into(),
+            parameters: vec![
+                ToolParamSchema::optional("document", "Limit verification to a single document slug."),
+                ToolParamSchema::optional(
+                    "repair",
+                    "If \"true\", re-ingest documents found to be corrupted.",
+                ),
+            ],
+            danger: DangerInfo {
+                level: DangerLevel::Cautious,
+                capabilities: HashSet::from([
+                    Capability::ReadFilesystem,
+                    Capability::ReadKg,
+                    Capability::VsaAccess,
+                    Capability::WriteKg,
+                ]),
+                description: "Reads library source files and, when repairing, re-ingests \
+                              documents into the knowledge graph."
+                    .into(),
+                shadow_triggers: vec!["verify".into(), "integrity".into()],
+            },
+            source: ToolSource::Native,
+        }
+    }
+}
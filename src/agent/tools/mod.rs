@@ -15,6 +15,7 @@ pub mod infer_rules;
 pub mod kg_mutate;
 pub mod kg_query;
 pub mod library_search;
+pub mod library_verify;
 pub mod memory_recall;
 pub mod reason;
 pub mod shell_exec;
@@ -36,11 +37,14 @@ pub use infer_rules::InferRulesTool;
 pub use kg_mutate::KgMutateTool;
 pub use kg_query::KgQueryTool;
 pub use library_search::LibrarySearchTool;
+pub use library_verify::LibraryVerifyTool;
 pub use memory_recall::MemoryRecallTool;
 pub use reason::ReasonTool;
 pub use shell_exec::ShellExecTool;
 pub use similarity_search::SimilaritySearchTool;
 pub use text_ingest::TextIngestTool;
 pub use user_interact::UserInteractTool;
-pub use agent_management::{AgentListTool, AgentMessageTool, AgentRetireTool, AgentSpawnTool};
+pub use agent_management::{
+    AgentBatchTool, AgentListTool, AgentMessageTool, AgentRetireTool, AgentSpawnTool,
+};
 pub use trigger_manage::TriggerManageTool;
@@ -106,6 +106,30 @@ pub enum VsaError {
         help("The HNSW approximate nearest-neighbor index encountered an internal error.")
     )]
     HnswError { message: String },
+
+    #[error("I/O error: {source}")]
+    #[diagnostic(
+        code(akh::vsa::io),
+        help(
+            "A filesystem operation on a compressed item-memory file failed. \
+             Check that the path exists, has correct permissions, and that the disk is not full."
+        )
+    )]
+    Io {
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("corrupt item-memory file: {message}")]
+    #[diagnostic(
+        code(akh::vsa::corrupt_file),
+        help(
+            "The compressed item-memory file's header or index doesn't match what \
+             `ItemMemory::save_compressed` writes — it may be truncated, written by an \
+             incompatible version, or not an item-memory file at all."
+        )
+    )]
+    CorruptFile { message: String },
 }
 
 // ---------------------------------------------------------------------------
@@ -557,6 +581,94 @@ pub enum PipelineError {
         help("The pipeline needs seed symbols to start. Provide initial Seeds data.")
     )]
     NoSeeds,
+
+    #[error("pipeline interrupted at stage {stage_index} ({stage_name})")]
+    #[diagnostic(
+        code(akh::pipeline::interrupted),
+        help(
+            "Execution was cancelled via the interrupt signal. Stages that \
+             completed before the interrupt are available in the partial result."
+        )
+    )]
+    Interrupted {
+        stage_name: String,
+        stage_index: usize,
+    },
+
+    #[error("pipeline {name} failed validation with {} problem(s)", errors.len())]
+    #[diagnostic(
+        code(akh::pipeline::invalid),
+        help(
+            "Run Pipeline::validate() to see every problem at once — data-flow \
+             mismatches are reported together rather than one at a time."
+        )
+    )]
+    ValidationFailed { name: String, errors: Vec<String> },
+
+    #[error("pipeline graph {name} has a cycle")]
+    #[diagnostic(
+        code(akh::pipeline::graph_cycle),
+        help(
+            "A PipelineGraph's node inputs must form a DAG. Check for a node \
+             that (directly or transitively) lists itself as an input."
+        )
+    )]
+    GraphCycle { name: String },
+
+    #[error("pipeline graph {name} has no output node")]
+    #[diagnostic(
+        code(akh::pipeline::graph_no_output),
+        help("Call PipelineGraph::set_output with the node whose result should be returned.")
+    )]
+    GraphNoOutput { name: String },
+}
+
+/// A single data-flow problem found by `Pipeline::validate` before a pipeline
+/// runs. Collected rather than returned on first mismatch, so a pipeline
+/// builder/UI can report every problem in one pass.
+#[derive(Debug, Error, Diagnostic)]
+pub enum PipelineValidationError {
+    #[error("pipeline {name} has no stages")]
+    #[diagnostic(
+        code(akh::pipeline::validate::empty),
+        help("A pipeline must contain at least one stage.")
+    )]
+    EmptyPipeline { name: String },
+
+    #[error(
+        "stage {stage_index} ({stage_name}) cannot accept seed data, but the \
+         pipeline's initial input is seeds"
+    )]
+    #[diagnostic(
+        code(akh::pipeline::validate::missing_seed_source),
+        help(
+            "The first stage of a pipeline receives the caller's initial data, \
+             here Seeds. Add a stage that accepts Seeds before this one, or reorder \
+             the pipeline so the seed-consuming stage runs first."
+        )
+    )]
+    MissingSeedSource {
+        stage_index: usize,
+        stage_name: String,
+    },
+
+    #[error(
+        "stage {stage_index} ({stage_name}) expects {expected}, but would \
+         receive {actual} from the previous stage"
+    )]
+    #[diagnostic(
+        code(akh::pipeline::validate::incompatible_stage),
+        help(
+            "Check the pipeline's stage ordering — each stage's declared input \
+             types must include the previous stage's output type."
+        )
+    )]
+    IncompatibleStage {
+        stage_index: usize,
+        stage_name: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -7,10 +7,17 @@
 //!
 //! Enforcement is opt-in: call `check_triple_constraints()` before `add_triple()`
 //! to get diagnostic errors for violations. Skippable for bootstrap/migration.
+//!
+//! Relations of arity > 2 can't fit in a plain `(subject, predicate, object)`
+//! triple, so [`ConstraintRegistry::add_nary`] reifies them: `between(A, B, C)`
+//! becomes a fresh statement node `S` with `(S, onto:arg1, A)`, `(S, onto:arg2, B)`,
+//! `(S, onto:arg3, C)`, and `(S, is-a, between)`.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
 
 use miette::Diagnostic;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -64,6 +71,103 @@ pub enum ArityError {
         expected_arity: usize,
         actual_arity: usize,
     },
+
+    #[error(
+        "cardinality violation: relation {relation_label} is single-valued (cardinality One), \
+         but subject {subject_label} already has object \"{existing_label}\" and a distinct \
+         object \"{new_label}\" was asserted"
+    )]
+    #[diagnostic(
+        code(akh::arity::cardinality_violation),
+        help(
+            "This relation is declared cardinality `One`, meaning each subject may have at \
+             most one distinct object for it. Retract the existing triple first if the value \
+             genuinely changed, or declare the relation `Many` if multiple objects are valid."
+        )
+    )]
+    CardinalityViolation {
+        relation_label: String,
+        subject_label: String,
+        existing_label: String,
+        new_label: String,
+        relation: SymbolId,
+        subject: SymbolId,
+        existing_object: SymbolId,
+        new_object: SymbolId,
+    },
+
+    #[error(
+        "datatype violation: argument 2 of relation {relation_label} expects a {expected:?} \
+         literal, but \"{actual_label}\" does not parse as one"
+    )]
+    #[diagnostic(
+        code(akh::arity::datatype_violation),
+        help(
+            "The object's label does not match the relation's declared literal datatype. \
+             Either assert a value in the expected format, or relax the declared \
+             `arg2_datatype` constraint."
+        )
+    )]
+    DatatypeViolation {
+        relation_label: String,
+        actual_label: String,
+        relation: SymbolId,
+        actual: SymbolId,
+        expected: ValueType,
+    },
+
+    #[error("failed to assert n-ary relation: {message}")]
+    #[diagnostic(
+        code(akh::arity::nary_assertion_failed),
+        help("Creating the reified statement node or one of its argument links failed. Check the underlying engine error.")
+    )]
+    NaryAssertionFailed { message: String },
+
+    #[error(
+        "disjoint type violation: {entity_label} is an instance of both \
+         \"{type_a_label}\" and \"{type_b_label}\", which are declared disjoint"
+    )]
+    #[diagnostic(
+        code(akh::arity::disjoint_type_violation),
+        help(
+            "Two declared-disjoint types can never share an instance. Retract one of the \
+             conflicting `is-a` links, or remove the `disjoint_with` declaration if the \
+             types genuinely overlap."
+        )
+    )]
+    DisjointTypeViolation {
+        entity_label: String,
+        type_a_label: String,
+        type_b_label: String,
+        entity: SymbolId,
+        type_a: SymbolId,
+        type_b: SymbolId,
+    },
+
+    #[error(
+        "confidence violation: argument {arg_position} of relation {relation_label} is an \
+         instance of \"{expected_label}\" with confidence {confidence:.3}, below the required \
+         threshold {threshold:.3}"
+    )]
+    #[diagnostic(
+        code(akh::arity::low_confidence_type_violation),
+        help(
+            "The `is-a` chain connecting this argument to the required type carries enough \
+             uncertainty that its combined (noisy-OR) confidence falls short of the relation's \
+             declared threshold. Strengthen the supporting evidence, or lower the threshold \
+             with `declare_with_confidence()` if the current evidence is acceptable."
+        )
+    )]
+    LowConfidenceTypeViolation {
+        relation_label: String,
+        expected_label: String,
+        arg_position: usize,
+        confidence: f32,
+        threshold: f32,
+        relation: SymbolId,
+        expected: SymbolId,
+        actual: SymbolId,
+    },
 }
 
 /// Result type for arity operations.
@@ -102,6 +206,42 @@ impl ArityPredicates {
 // Constraint declarations
 // ---------------------------------------------------------------------------
 
+/// How many distinct objects a subject may have for a given relation.
+///
+/// Mirrors Mentat/Datomic attribute cardinality: `One` makes a relation
+/// functional (e.g. `biologicalMother`), `Many` allows any number of
+/// objects (e.g. `knows`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Cardinality {
+    /// At most one distinct object per subject.
+    One,
+    /// Any number of objects per subject.
+    #[default]
+    Many,
+}
+
+/// The kind of scalar literal a relation's object is expected to hold.
+///
+/// Modeled on Mentat/cozo attribute typing: unlike `arg1_type`/`arg2_type`,
+/// which require an `is-a` link to an entity symbol, a datatype constraint
+/// validates the *label text* of a literal object symbol directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValueType {
+    /// A signed integer, e.g. `"42"`.
+    Int,
+    /// A floating-point number, e.g. `"3.14"`.
+    Float,
+    /// An unconstrained string — always matches.
+    String,
+    /// A UUID, e.g. `"550e8400-e29b-41d4-a716-446655440000"`.
+    Uuid,
+    /// An RFC 3339-style timestamp, e.g. `"2024-01-01T00:00:00Z"`; the UTC
+    /// offset is optional.
+    Timestamp,
+    /// A `0x`-prefixed hex byte string, e.g. `"0xdeadbeef"`.
+    Bytes,
+}
+
 /// Declared constraints for a relation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RelationConstraint {
@@ -109,10 +249,23 @@ pub struct RelationConstraint {
     pub relation: SymbolId,
     /// Declared arity (standard triples are binary = 2).
     pub arity: usize,
-    /// Required type for argument 1 (subject). `None` means unconstrained.
-    pub arg1_type: Option<SymbolId>,
-    /// Required type for argument 2 (object). `None` means unconstrained.
-    pub arg2_type: Option<SymbolId>,
+    /// Required type per argument position (0 = subject/arg1, 1 = object/arg2,
+    /// 2.. = further arguments of a reified n-ary relation). `None` at a
+    /// position, or a position past the end of the vec, means unconstrained.
+    pub arg_types: Vec<Option<SymbolId>>,
+    /// How many distinct objects a subject may have for this relation.
+    #[serde(default)]
+    pub cardinality: Cardinality,
+    /// Required literal datatype for argument 2 (object). `None` means
+    /// unconstrained (the usual case, where the object is an entity symbol
+    /// rather than a literal).
+    #[serde(default)]
+    pub arg2_datatype: Option<ValueType>,
+    /// Minimum confidence required for a type membership to count as
+    /// satisfied, per [`instance_of_confidence`]. `None` falls back to the
+    /// plain boolean [`is_instance_of`] check.
+    #[serde(default)]
+    pub min_confidence: Option<f32>,
 }
 
 /// A detected constraint violation.
@@ -131,6 +284,35 @@ pub enum ConstraintViolation {
         expected_type: SymbolId,
         actual_symbol: SymbolId,
     },
+    /// A cardinality-`One` relation was asserted with a second distinct object.
+    Cardinality {
+        relation: SymbolId,
+        subject: SymbolId,
+        existing_object: SymbolId,
+        new_object: SymbolId,
+    },
+    /// An object literal's label does not parse as the declared datatype.
+    Datatype {
+        relation: SymbolId,
+        actual: SymbolId,
+        expected: ValueType,
+    },
+    /// An entity is an instance of two declared-disjoint types.
+    DisjointType {
+        entity: SymbolId,
+        type_a: SymbolId,
+        type_b: SymbolId,
+    },
+    /// An argument is an instance of the required type, but only below the
+    /// relation's declared confidence threshold.
+    LowConfidenceType {
+        relation: SymbolId,
+        arg_position: usize,
+        expected_type: SymbolId,
+        actual_symbol: SymbolId,
+        confidence: f32,
+        threshold: f32,
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -144,6 +326,10 @@ pub enum ConstraintViolation {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConstraintRegistry {
     constraints: HashMap<SymbolId, RelationConstraint>,
+    /// Symmetric pairs of types that can never share an instance, normalized
+    /// so `(a, b)` with `a <= b`.
+    #[serde(default)]
+    disjoint_pairs: HashSet<(SymbolId, SymbolId)>,
 }
 
 impl ConstraintRegistry {
@@ -152,7 +338,7 @@ impl ConstraintRegistry {
         Self::default()
     }
 
-    /// Declare constraints for a relation.
+    /// Declare constraints for a relation, defaulting to cardinality `Many`.
     pub fn declare(
         &mut self,
         relation: SymbolId,
@@ -160,13 +346,83 @@ impl ConstraintRegistry {
         arg1_type: Option<SymbolId>,
         arg2_type: Option<SymbolId>,
     ) {
+        self.declare_with_cardinality(relation, arity, arg1_type, arg2_type, Cardinality::Many);
+    }
+
+    /// Declare constraints for a relation with an explicit cardinality.
+    pub fn declare_with_cardinality(
+        &mut self,
+        relation: SymbolId,
+        arity: usize,
+        arg1_type: Option<SymbolId>,
+        arg2_type: Option<SymbolId>,
+        cardinality: Cardinality,
+    ) {
+        self.declare_with_datatype(relation, arity, arg1_type, arg2_type, cardinality, None);
+    }
+
+    /// Declare constraints for a relation with an explicit cardinality and a
+    /// required literal datatype for argument 2.
+    pub fn declare_with_datatype(
+        &mut self,
+        relation: SymbolId,
+        arity: usize,
+        arg1_type: Option<SymbolId>,
+        arg2_type: Option<SymbolId>,
+        cardinality: Cardinality,
+        arg2_datatype: Option<ValueType>,
+    ) {
+        self.declare_with_confidence(
+            relation,
+            arity,
+            arg1_type,
+            arg2_type,
+            cardinality,
+            arg2_datatype,
+            None,
+        );
+    }
+
+    /// Declare constraints for a relation with an explicit cardinality, a
+    /// required literal datatype for argument 2, and a minimum confidence
+    /// for argument type membership (see [`instance_of_confidence`]).
+    pub fn declare_with_confidence(
+        &mut self,
+        relation: SymbolId,
+        arity: usize,
+        arg1_type: Option<SymbolId>,
+        arg2_type: Option<SymbolId>,
+        cardinality: Cardinality,
+        arg2_datatype: Option<ValueType>,
+        min_confidence: Option<f32>,
+    ) {
+        self.constraints.insert(
+            relation,
+            RelationConstraint {
+                relation,
+                arity,
+                arg_types: vec![arg1_type, arg2_type],
+                cardinality,
+                arg2_datatype,
+                min_confidence,
+            },
+        );
+    }
+
+    /// Declare an n-ary (arity > 2) relation, reified via [`ConstraintRegistry::add_nary`].
+    ///
+    /// `arg_types[i]` constrains argument `i + 1`; `None` (or a missing
+    /// position) means unconstrained.
+    pub fn declare_nary(&mut self, relation: SymbolId, arity: usize, arg_types: Vec<Option<SymbolId>>) {
         self.constraints.insert(
             relation,
             RelationConstraint {
                 relation,
                 arity,
-                arg1_type,
-                arg2_type,
+                arg_types,
+                cardinality: Cardinality::Many,
+                arg2_datatype: None,
+                min_confidence: None,
             },
         );
     }
@@ -176,6 +432,17 @@ impl ConstraintRegistry {
         self.constraints.get(&relation)
     }
 
+    /// Declare two types as mutually disjoint: no entity may be an instance
+    /// of both at once.
+    pub fn declare_disjoint(&mut self, type_a: SymbolId, type_b: SymbolId) {
+        let pair = if type_a <= type_b {
+            (type_a, type_b)
+        } else {
+            (type_b, type_a)
+        };
+        self.disjoint_pairs.insert(pair);
+    }
+
     /// Check a triple against declared constraints.
     ///
     /// Returns a list of violations (empty = no violations).
@@ -201,25 +468,80 @@ impl ConstraintRegistry {
         }
 
         // Check arg1 type (subject)
-        if let Some(required_type) = constraint.arg1_type {
-            if !is_instance_of(engine, triple.subject, required_type) {
-                violations.push(ConstraintViolation::Type {
+        if let Some(required_type) = constraint.arg_types.first().copied().flatten() {
+            let satisfied = self.check_required_type(
+                engine,
+                triple.subject,
+                required_type,
+                constraint.min_confidence,
+                triple.predicate,
+                1,
+                &mut violations,
+            );
+            if satisfied {
+                if let Some(other_type) =
+                    self.find_disjoint_violation(engine, triple.subject, required_type)
+                {
+                    violations.push(ConstraintViolation::DisjointType {
+                        entity: triple.subject,
+                        type_a: required_type,
+                        type_b: other_type,
+                    });
+                }
+            }
+        }
+
+        // Check arg2 type (object)
+        if let Some(required_type) = constraint.arg_types.get(1).copied().flatten() {
+            let satisfied = self.check_required_type(
+                engine,
+                triple.object,
+                required_type,
+                constraint.min_confidence,
+                triple.predicate,
+                2,
+                &mut violations,
+            );
+            if satisfied {
+                if let Some(other_type) =
+                    self.find_disjoint_violation(engine, triple.object, required_type)
+                {
+                    violations.push(ConstraintViolation::DisjointType {
+                        entity: triple.object,
+                        type_a: required_type,
+                        type_b: other_type,
+                    });
+                }
+            }
+        }
+
+        // Check cardinality: a `One` relation may not have a second distinct
+        // object for the same subject.
+        if constraint.cardinality == Cardinality::One {
+            let existing = engine
+                .knowledge_graph()
+                .triples_from(triple.subject)
+                .into_iter()
+                .find(|t| t.predicate == triple.predicate && t.object != triple.object);
+
+            if let Some(t) = existing {
+                violations.push(ConstraintViolation::Cardinality {
                     relation: triple.predicate,
-                    arg_position: 1,
-                    expected_type: required_type,
-                    actual_symbol: triple.subject,
+                    subject: triple.subject,
+                    existing_object: t.object,
+                    new_object: triple.object,
                 });
             }
         }
 
-        // Check arg2 type (object)
-        if let Some(required_type) = constraint.arg2_type {
-            if !is_instance_of(engine, triple.object, required_type) {
-                violations.push(ConstraintViolation::Type {
+        // Check arg2 datatype (object literal kind)
+        if let Some(expected) = constraint.arg2_datatype {
+            let label = engine.resolve_label(triple.object);
+            if !matches_datatype(&label, expected) {
+                violations.push(ConstraintViolation::Datatype {
                     relation: triple.predicate,
-                    arg_position: 2,
-                    expected_type: required_type,
-                    actual_symbol: triple.object,
+                    actual: triple.object,
+                    expected,
                 });
             }
         }
@@ -259,12 +581,245 @@ impl ConstraintRegistry {
                     expected: *expected_type,
                     actual: *actual_symbol,
                 }),
+                ConstraintViolation::Cardinality {
+                    relation,
+                    subject,
+                    existing_object,
+                    new_object,
+                } => Err(ArityError::CardinalityViolation {
+                    relation_label: engine.resolve_label(*relation),
+                    subject_label: engine.resolve_label(*subject),
+                    existing_label: engine.resolve_label(*existing_object),
+                    new_label: engine.resolve_label(*new_object),
+                    relation: *relation,
+                    subject: *subject,
+                    existing_object: *existing_object,
+                    new_object: *new_object,
+                }),
+                ConstraintViolation::Datatype {
+                    relation,
+                    actual,
+                    expected,
+                } => Err(ArityError::DatatypeViolation {
+                    relation_label: engine.resolve_label(*relation),
+                    actual_label: engine.resolve_label(*actual),
+                    relation: *relation,
+                    actual: *actual,
+                    expected: *expected,
+                }),
+                ConstraintViolation::DisjointType {
+                    entity,
+                    type_a,
+                    type_b,
+                } => Err(ArityError::DisjointTypeViolation {
+                    entity_label: engine.resolve_label(*entity),
+                    type_a_label: engine.resolve_label(*type_a),
+                    type_b_label: engine.resolve_label(*type_b),
+                    entity: *entity,
+                    type_a: *type_a,
+                    type_b: *type_b,
+                }),
+                ConstraintViolation::LowConfidenceType {
+                    relation,
+                    arg_position,
+                    expected_type,
+                    actual_symbol,
+                    confidence,
+                    threshold,
+                } => Err(ArityError::LowConfidenceTypeViolation {
+                    relation_label: engine.resolve_label(*relation),
+                    expected_label: engine.resolve_label(*expected_type),
+                    arg_position: *arg_position,
+                    confidence: *confidence,
+                    threshold: *threshold,
+                    relation: *relation,
+                    expected: *expected_type,
+                    actual: *actual_symbol,
+                }),
             }
         } else {
             Ok(())
         }
     }
 
+    /// Assert an n-ary (arity > 2) relation via reification.
+    ///
+    /// Creates a fresh "statement" entity `S` (reused if the exact same
+    /// relation/arguments combination was asserted before) and links it with
+    /// `(S, onto:argI, argI)` for each argument plus `(S, is-a, relation)`.
+    /// Each argument is validated against the per-position type constraint
+    /// declared for `relation` via [`ConstraintRegistry::declare_nary`], if any.
+    pub fn add_nary(
+        &self,
+        engine: &Engine,
+        relation: SymbolId,
+        args: &[SymbolId],
+    ) -> ArityResult<SymbolId> {
+        if let Some(constraint) = self.constraints.get(&relation) {
+            if args.len() != constraint.arity {
+                return Err(ArityError::ArityViolation {
+                    relation_label: engine.resolve_label(relation),
+                    expected_arity: constraint.arity,
+                    actual_arity: args.len(),
+                });
+            }
+
+            for (i, arg) in args.iter().enumerate() {
+                if let Some(Some(expected_type)) = constraint.arg_types.get(i) {
+                    if !is_instance_of(engine, *arg, *expected_type) {
+                        return Err(ArityError::TypeViolation {
+                            relation_label: engine.resolve_label(relation),
+                            arg_position: i + 1,
+                            expected_label: engine.resolve_label(*expected_type),
+                            actual_label: engine.resolve_label(*arg),
+                            relation,
+                            expected: *expected_type,
+                            actual: *arg,
+                        });
+                    }
+                }
+            }
+        }
+
+        let relation_label = engine.resolve_label(relation);
+        let stmt_label = format!(
+            "stmt:{relation_label}({})",
+            args.iter()
+                .map(|a| a.get().to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        let statement = match engine.lookup_symbol(&stmt_label) {
+            Ok(id) => id,
+            Err(_) => engine
+                .create_symbol(crate::symbol::SymbolKind::Composite, &stmt_label)
+                .map_err(|e| ArityError::NaryAssertionFailed {
+                    message: format!("failed to create statement node \"{stmt_label}\": {e}"),
+                })?
+                .id,
+        };
+
+        let is_a = engine
+            .resolve_or_create_relation("is-a")
+            .map_err(|e| ArityError::NaryAssertionFailed {
+                message: format!("failed to resolve is-a: {e}"),
+            })?;
+        engine
+            .add_triple(&Triple::new(statement, is_a, relation))
+            .map_err(|e| ArityError::NaryAssertionFailed {
+                message: format!("failed to link statement to relation {relation_label}: {e}"),
+            })?;
+
+        for (i, arg) in args.iter().enumerate() {
+            let arg_pred = engine
+                .resolve_or_create_relation(&format!("onto:arg{}", i + 1))
+                .map_err(|e| ArityError::NaryAssertionFailed {
+                    message: format!("failed to resolve onto:arg{}: {e}", i + 1),
+                })?;
+            engine
+                .add_triple(&Triple::new(statement, arg_pred, *arg))
+                .map_err(|e| ArityError::NaryAssertionFailed {
+                    message: format!("failed to assert argument {}: {e}", i + 1),
+                })?;
+        }
+
+        Ok(statement)
+    }
+
+    /// Check whether `entity` satisfies a required type, pushing a `Type` or
+    /// `LowConfidenceType` violation if not. Returns whether the entity
+    /// counts as an instance (a below-threshold soft violation still counts,
+    /// so callers can go on to check disjointness).
+    #[allow(clippy::too_many_arguments)]
+    fn check_required_type(
+        &self,
+        engine: &Engine,
+        entity: SymbolId,
+        required_type: SymbolId,
+        min_confidence: Option<f32>,
+        relation: SymbolId,
+        arg_position: usize,
+        violations: &mut Vec<ConstraintViolation>,
+    ) -> bool {
+        let satisfied = match min_confidence {
+            Some(threshold) => match instance_of_confidence(engine, entity, required_type) {
+                Some(confidence) if confidence >= threshold => true,
+                Some(confidence) => {
+                    violations.push(ConstraintViolation::LowConfidenceType {
+                        relation,
+                        arg_position,
+                        expected_type: required_type,
+                        actual_symbol: entity,
+                        confidence,
+                        threshold,
+                    });
+                    true
+                }
+                None => false,
+            },
+            None => is_instance_of(engine, entity, required_type),
+        };
+
+        if !satisfied {
+            violations.push(ConstraintViolation::Type {
+                relation,
+                arg_position,
+                expected_type: required_type,
+                actual_symbol: entity,
+            });
+        }
+
+        satisfied
+    }
+
+    /// Walk every `is-a` edge of `entity` (transitively) and report every
+    /// declared-disjoint type pair it simultaneously belongs to.
+    ///
+    /// Useful for auditing a migrated graph for contradictions such as
+    /// "X is both an Animal and a Country" that weren't caught at assertion
+    /// time (e.g. because the constraints were declared after the fact).
+    pub fn check_symbol_consistency(
+        &self,
+        engine: &Engine,
+        entity: SymbolId,
+    ) -> Vec<ConstraintViolation> {
+        let types = is_a_closure(engine, entity);
+        self.disjoint_pairs
+            .iter()
+            .filter(|(a, b)| types.contains(a) && types.contains(b))
+            .map(|&(type_a, type_b)| ConstraintViolation::DisjointType {
+                entity,
+                type_a,
+                type_b,
+            })
+            .collect()
+    }
+
+    /// If `entity` (already confirmed an instance of `confirmed_type`) is also
+    /// an instance of a type declared disjoint with `confirmed_type`, return
+    /// that conflicting type.
+    fn find_disjoint_violation(
+        &self,
+        engine: &Engine,
+        entity: SymbolId,
+        confirmed_type: SymbolId,
+    ) -> Option<SymbolId> {
+        if self.disjoint_pairs.is_empty() {
+            return None;
+        }
+        let types = is_a_closure(engine, entity);
+        self.disjoint_pairs.iter().find_map(|&(a, b)| {
+            if a == confirmed_type && types.contains(&b) {
+                Some(b)
+            } else if b == confirmed_type && types.contains(&a) {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Number of declared constraints.
     pub fn len(&self) -> usize {
         self.constraints.len()
@@ -276,6 +831,33 @@ impl ConstraintRegistry {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Datatype checking helpers
+// ---------------------------------------------------------------------------
+
+static RE_UUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$").unwrap()
+});
+
+static RE_TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(?:\.\d+)?(?:Z|[+-]\d{2}:\d{2})?$").unwrap()
+});
+
+static RE_BYTES: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^0x(?:[0-9a-fA-F]{2})+$").unwrap());
+
+/// Check whether a literal's label text matches the declared [`ValueType`].
+fn matches_datatype(label: &str, expected: ValueType) -> bool {
+    match expected {
+        ValueType::Int => label.parse::<i64>().is_ok(),
+        ValueType::Float => label.parse::<f64>().is_ok(),
+        ValueType::String => true,
+        ValueType::Uuid => RE_UUID.is_match(label),
+        ValueType::Timestamp => RE_TIMESTAMP.is_match(label),
+        ValueType::Bytes => RE_BYTES.is_match(label),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Type checking helpers
 // ---------------------------------------------------------------------------
@@ -325,6 +907,140 @@ fn is_instance_of(engine: &Engine, entity: SymbolId, type_id: SymbolId) -> bool
     false
 }
 
+/// Maximum `is-a` chain depth explored by [`instance_of_confidence`], to
+/// bound cycles in the `is-a` graph.
+const MAX_CONFIDENCE_DEPTH: usize = 16;
+
+/// A node queued for confidence-weighted `is-a` relaxation, ordered by
+/// confidence so [`BinaryHeap`] pops the most-confident path first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ConfidenceNode {
+    confidence: f32,
+    node: SymbolId,
+    depth: usize,
+}
+
+impl Eq for ConfidenceNode {}
+
+impl PartialOrd for ConfidenceNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ConfidenceNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.confidence.total_cmp(&other.confidence)
+    }
+}
+
+/// Confidence-weighted instance-of check.
+///
+/// Walks the `is-a` chain the same way [`is_instance_of`] does, but treats
+/// each edge's [`Triple::confidence`] as a probability instead of certain
+/// truth. A single path's confidence is the product of its edges'
+/// confidences; when `type_id` is reachable by more than one path, the
+/// results combine by noisy-OR: `1 - Π(1 - path_confidence)`.
+///
+/// Uses Dijkstra-style relaxation — a node is only re-expanded once a
+/// higher-confidence path to it is found — rather than plain BFS, and caps
+/// path depth at [`MAX_CONFIDENCE_DEPTH`] to bound cycles. Returns `None` if
+/// `type_id` is not reachable at all.
+pub fn instance_of_confidence(engine: &Engine, entity: SymbolId, type_id: SymbolId) -> Option<f32> {
+    if entity == type_id {
+        return Some(1.0);
+    }
+
+    let kg = engine.knowledge_graph();
+    let is_a = engine.lookup_symbol("is-a").ok()?;
+
+    let mut best: HashMap<SymbolId, f32> = HashMap::new();
+    best.insert(entity, 1.0);
+
+    let mut heap = std::collections::BinaryHeap::new();
+    heap.push(ConfidenceNode {
+        confidence: 1.0,
+        node: entity,
+        depth: 0,
+    });
+
+    let mut path_confidences: Vec<f32> = Vec::new();
+
+    while let Some(ConfidenceNode {
+        confidence,
+        node,
+        depth,
+    }) = heap.pop()
+    {
+        if depth >= MAX_CONFIDENCE_DEPTH {
+            continue;
+        }
+        // Stale entry: a better path to `node` was already relaxed.
+        if confidence < *best.get(&node).unwrap_or(&f32::MIN) {
+            continue;
+        }
+
+        for t in kg
+            .triples_from(node)
+            .into_iter()
+            .filter(|t| t.predicate == is_a)
+        {
+            let candidate = confidence * t.confidence;
+            if t.object == type_id {
+                path_confidences.push(candidate);
+            }
+            if candidate > *best.get(&t.object).unwrap_or(&0.0) {
+                best.insert(t.object, candidate);
+                heap.push(ConfidenceNode {
+                    confidence: candidate,
+                    node: t.object,
+                    depth: depth + 1,
+                });
+            }
+        }
+    }
+
+    if path_confidences.is_empty() {
+        return None;
+    }
+
+    let noisy_or = 1.0 - path_confidences.iter().fold(1.0f32, |acc, p| acc * (1.0 - p));
+    Some(noisy_or.clamp(0.0, 1.0))
+}
+
+/// Collect the full set of types reachable from `entity` via transitive
+/// `is-a` edges. Unlike [`is_instance_of`], `entity` itself is not included
+/// unless it has an explicit `is-a` link back to itself.
+fn is_a_closure(engine: &Engine, entity: SymbolId) -> HashSet<SymbolId> {
+    let kg = engine.knowledge_graph();
+
+    let is_a = match engine.lookup_symbol("is-a") {
+        Ok(sym) => sym,
+        Err(_) => return HashSet::new(),
+    };
+
+    let mut visited = HashSet::new();
+    let mut types = HashSet::new();
+    let mut queue = std::collections::VecDeque::new();
+    queue.push_back(entity);
+
+    while let Some(current) = queue.pop_front() {
+        if !visited.insert(current) {
+            continue;
+        }
+        for t in kg
+            .triples_from(current)
+            .into_iter()
+            .filter(|t| t.predicate == is_a)
+        {
+            types.insert(t.object);
+            queue.push_back(t.object);
+        }
+    }
+
+    types
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -505,6 +1221,448 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn cardinality_one_rejects_distinct_second_object() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+
+        let birthplace = engine
+            .create_symbol(SymbolKind::Relation, "birthplace")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_cardinality(birthplace.id, 2, None, None, Cardinality::One);
+
+        engine
+            .add_triple(&Triple::new(alice, birthplace.id, france))
+            .unwrap();
+
+        let germany = engine.create_symbol(SymbolKind::Entity, "Germany").unwrap();
+        let triple = Triple::new(alice, birthplace.id, germany.id);
+        let violations = registry.check_triple(&triple, &engine);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::Cardinality {
+                existing_object,
+                new_object,
+                ..
+            } if existing_object == france && new_object == germany.id
+        ));
+
+        let err = registry.check_triple_or_err(&triple, &engine).unwrap_err();
+        assert!(matches!(err, ArityError::CardinalityViolation { .. }));
+    }
+
+    #[test]
+    fn cardinality_one_allows_reasserting_same_object() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+
+        let birthplace = engine
+            .create_symbol(SymbolKind::Relation, "birthplace")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_cardinality(birthplace.id, 2, None, None, Cardinality::One);
+
+        engine
+            .add_triple(&Triple::new(alice, birthplace.id, france))
+            .unwrap();
+
+        let triple = Triple::new(alice, birthplace.id, france);
+        let violations = registry.check_triple(&triple, &engine);
+        assert!(violations.is_empty(), "violations: {violations:?}");
+    }
+
+    #[test]
+    fn default_cardinality_many_allows_multiple_objects() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+
+        let knows = engine
+            .create_symbol(SymbolKind::Relation, "visited")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare(knows.id, 2, None, None);
+
+        engine
+            .add_triple(&Triple::new(alice, knows.id, france))
+            .unwrap();
+
+        let germany = engine.create_symbol(SymbolKind::Entity, "Germany").unwrap();
+        let triple = Triple::new(alice, knows.id, germany.id);
+        let violations = registry.check_triple(&triple, &engine);
+        assert!(violations.is_empty(), "violations: {violations:?}");
+    }
+
+    #[test]
+    fn datatype_constraint_rejects_non_matching_literal() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+
+        let born_on = engine
+            .create_symbol(SymbolKind::Relation, "bornOn")
+            .unwrap();
+        let bad_date = engine
+            .create_symbol(SymbolKind::Entity, "not-a-date")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_datatype(
+            born_on.id,
+            2,
+            None,
+            None,
+            Cardinality::Many,
+            Some(ValueType::Timestamp),
+        );
+
+        let triple = Triple::new(alice, born_on.id, bad_date.id);
+        let violations = registry.check_triple(&triple, &engine);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::Datatype {
+                expected: ValueType::Timestamp,
+                ..
+            }
+        ));
+
+        let err = registry.check_triple_or_err(&triple, &engine).unwrap_err();
+        assert!(matches!(err, ArityError::DatatypeViolation { .. }));
+    }
+
+    #[test]
+    fn datatype_constraint_accepts_matching_literal() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+
+        let born_on = engine
+            .create_symbol(SymbolKind::Relation, "bornOn")
+            .unwrap();
+        let good_date = engine
+            .create_symbol(SymbolKind::Entity, "1990-05-12T00:00:00Z")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_datatype(
+            born_on.id,
+            2,
+            None,
+            None,
+            Cardinality::Many,
+            Some(ValueType::Timestamp),
+        );
+
+        let triple = Triple::new(alice, born_on.id, good_date.id);
+        let violations = registry.check_triple(&triple, &engine);
+        assert!(violations.is_empty(), "violations: {violations:?}");
+    }
+
+    #[test]
+    fn add_nary_reifies_statement_node() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+        let alice2 = engine.create_symbol(SymbolKind::Entity, "Alice2").unwrap();
+
+        let between = engine
+            .create_symbol(SymbolKind::Relation, "between")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_nary(between.id, 3, vec![None, None, None]);
+
+        let statement = registry
+            .add_nary(&engine, between.id, &[alice, france, alice2.id])
+            .unwrap();
+
+        assert!(is_instance_of(&engine, statement, between.id));
+
+        let arg1_pred = engine.lookup_symbol("onto:arg1").unwrap();
+        let links: Vec<_> = engine
+            .knowledge_graph()
+            .triples_from(statement)
+            .into_iter()
+            .filter(|t| t.predicate == arg1_pred)
+            .collect();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].object, alice);
+    }
+
+    #[test]
+    fn add_nary_rejects_wrong_argument_type() {
+        let (engine, _preds) = setup_typed_scenario();
+        let france = engine.lookup_symbol("France").unwrap();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let person = engine.lookup_symbol("Person").unwrap();
+
+        let between = engine
+            .create_symbol(SymbolKind::Relation, "between")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_nary(between.id, 3, vec![Some(person), Some(person), Some(person)]);
+
+        let err = registry
+            .add_nary(&engine, between.id, &[alice, france, alice])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ArityError::TypeViolation {
+                arg_position: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn add_nary_rejects_wrong_argument_count() {
+        let (engine, _preds) = setup_typed_scenario();
+        let france = engine.lookup_symbol("France").unwrap();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+
+        let between = engine
+            .create_symbol(SymbolKind::Relation, "between")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_nary(between.id, 3, vec![None, None, None]);
+
+        let err = registry
+            .add_nary(&engine, between.id, &[alice, france])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ArityError::ArityViolation {
+                expected_arity: 3,
+                actual_arity: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn binary_assertion_against_nary_relation_is_still_an_arity_violation() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+
+        let between = engine
+            .create_symbol(SymbolKind::Relation, "between")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_nary(between.id, 3, vec![]);
+
+        let triple = Triple::new(alice, between.id, alice);
+        let violations = registry.check_triple(&triple, &engine);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::Arity {
+                expected: 3,
+                actual: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn disjoint_type_detected_via_type_constraint() {
+        let (engine, preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+        let country = engine.lookup_symbol("Country").unwrap();
+
+        // Alice is also (incorrectly) a Country.
+        engine
+            .add_triple(&Triple::new(alice, preds.is_a, country))
+            .unwrap();
+
+        let lives_in = engine
+            .create_symbol(SymbolKind::Relation, "livesIn")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare(lives_in.id, 2, Some(animal), None);
+        registry.declare_disjoint(animal, country);
+
+        let france = engine.lookup_symbol("France").unwrap();
+        let triple = Triple::new(alice, lives_in.id, france);
+        let violations = registry.check_triple(&triple, &engine);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::DisjointType { .. }
+        ));
+
+        let err = registry.check_triple_or_err(&triple, &engine).unwrap_err();
+        assert!(matches!(err, ArityError::DisjointTypeViolation { .. }));
+    }
+
+    #[test]
+    fn check_symbol_consistency_finds_contradiction() {
+        let (engine, preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+        let country = engine.lookup_symbol("Country").unwrap();
+
+        engine
+            .add_triple(&Triple::new(alice, preds.is_a, country))
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_disjoint(animal, country);
+
+        let violations = registry.check_symbol_consistency(&engine, alice);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::DisjointType { entity, .. } if entity == alice
+        ));
+    }
+
+    #[test]
+    fn check_symbol_consistency_clean_entity_has_no_violations() {
+        let (engine, _preds) = setup_typed_scenario();
+        let france = engine.lookup_symbol("France").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+        let country = engine.lookup_symbol("Country").unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_disjoint(animal, country);
+
+        let violations = registry.check_symbol_consistency(&engine, france);
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn instance_of_confidence_direct_is_one() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+
+        assert_eq!(instance_of_confidence(&engine, alice, alice), Some(1.0));
+    }
+
+    #[test]
+    fn instance_of_confidence_single_path() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let person = engine.lookup_symbol("Person").unwrap();
+
+        // Alice is-a Person was asserted with full confidence in setup.
+        let conf = instance_of_confidence(&engine, alice, person).unwrap();
+        assert!((conf - 1.0).abs() < 1e-6, "conf = {conf}");
+    }
+
+    #[test]
+    fn instance_of_confidence_combines_multiple_paths_via_noisy_or() {
+        let (engine, preds) = setup_typed_scenario();
+
+        let cryptid = engine.create_symbol(SymbolKind::Entity, "Cryptid").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+        let legend = engine.create_symbol(SymbolKind::Entity, "Legend").unwrap();
+
+        // Two independent, uncertain paths from Cryptid to Animal.
+        engine
+            .add_triple(
+                &Triple::new(cryptid.id, preds.is_a, animal).with_confidence(0.5),
+            )
+            .unwrap();
+        engine
+            .add_triple(&Triple::new(cryptid.id, preds.is_a, legend.id).with_confidence(0.8))
+            .unwrap();
+        engine
+            .add_triple(&Triple::new(legend.id, preds.is_a, animal).with_confidence(0.5))
+            .unwrap();
+
+        // Paths: direct (0.5), and via Legend (0.8 * 0.5 = 0.4).
+        // Noisy-OR: 1 - (1 - 0.5) * (1 - 0.4) = 1 - 0.5 * 0.6 = 0.7.
+        let conf = instance_of_confidence(&engine, cryptid.id, animal).unwrap();
+        assert!((conf - 0.7).abs() < 1e-4, "conf = {conf}");
+    }
+
+    #[test]
+    fn instance_of_confidence_unreachable_is_none() {
+        let (engine, _preds) = setup_typed_scenario();
+        let france = engine.lookup_symbol("France").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+
+        assert_eq!(instance_of_confidence(&engine, france, animal), None);
+    }
+
+    #[test]
+    fn check_triple_flags_low_confidence_as_soft_violation() {
+        let (engine, preds) = setup_typed_scenario();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+
+        // A fresh entity whose only type link is a low-confidence is-a Animal.
+        let shaky = engine.create_symbol(SymbolKind::Entity, "ShakyAlice").unwrap();
+        engine
+            .add_triple(&Triple::new(shaky.id, preds.is_a, animal).with_confidence(0.2))
+            .unwrap();
+
+        let lives_in = engine
+            .create_symbol(SymbolKind::Relation, "livesIn")
+            .unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_confidence(
+            lives_in.id,
+            2,
+            Some(animal),
+            None,
+            Cardinality::Many,
+            None,
+            Some(0.9),
+        );
+
+        let triple = Triple::new(shaky.id, lives_in.id, france);
+        let violations = registry.check_triple(&triple, &engine);
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0],
+            ConstraintViolation::LowConfidenceType { .. }
+        ));
+
+        let err = registry.check_triple_or_err(&triple, &engine).unwrap_err();
+        assert!(matches!(err, ArityError::LowConfidenceTypeViolation { .. }));
+    }
+
+    #[test]
+    fn check_triple_passes_confidence_threshold_met() {
+        let (engine, _preds) = setup_typed_scenario();
+        let alice = engine.lookup_symbol("Alice").unwrap();
+        let animal = engine.lookup_symbol("Animal").unwrap();
+        let france = engine.lookup_symbol("France").unwrap();
+
+        // Alice -> Person -> Animal, both full confidence, so confidence is 1.0.
+        let lives_in = engine
+            .create_symbol(SymbolKind::Relation, "livesIn")
+            .unwrap();
+
+        let mut registry = ConstraintRegistry::new();
+        registry.declare_with_confidence(
+            lives_in.id,
+            2,
+            Some(animal),
+            None,
+            Cardinality::Many,
+            None,
+            Some(0.9),
+        );
+
+        let triple = Triple::new(alice, lives_in.id, france);
+        let violations = registry.check_triple(&triple, &engine);
+        assert!(violations.is_empty(), "violations: {violations:?}");
+    }
+
     #[test]
     fn registry_len_and_empty() {
         let mut reg = ConstraintRegistry::new();
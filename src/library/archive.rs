@@ -0,0 +1,121 @@
+//! Archive container detection and extraction.
+//!
+//! Archives are identified by sniffing their leading bytes rather than
+//! trusting a `.zip`/`.tar.gz`/etc. extension, the same approach
+//! `parser::detect_format_from_content_type` takes for HTTP responses.
+
+use std::io::Read;
+
+use crate::library::error::{LibraryError, LibraryResult};
+
+/// A supported archive/compression container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    TarGz,
+    TarZst,
+    TarXz,
+    TarBz2,
+}
+
+/// Sniff the archive kind from the leading bytes of `data`, or `None` if it
+/// isn't a recognized archive.
+fn sniff(data: &[u8]) -> Option<ArchiveKind> {
+    if data.starts_with(b"PK\x03\x04") || data.starts_with(b"PK\x05\x06") {
+        return Some(ArchiveKind::Zip);
+    }
+    if data.starts_with(&[0x1f, 0x8b]) {
+        return Some(ArchiveKind::TarGz);
+    }
+    if data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        return Some(ArchiveKind::TarZst);
+    }
+    if data.starts_with(&[0xfd, 0x37, 0x7a]) {
+        return Some(ArchiveKind::TarXz);
+    }
+    if data.starts_with(&[0x42, 0x5a, 0x68]) {
+        return Some(ArchiveKind::TarBz2);
+    }
+    // Uncompressed tar has no leading magic of its own; check for the
+    // "ustar" marker at its fixed header offset instead.
+    if data.len() > 262 && &data[257..262] == b"ustar" {
+        return Some(ArchiveKind::Tar);
+    }
+    None
+}
+
+/// Whether `data` looks like a recognized archive container.
+pub fn is_archive(data: &[u8]) -> bool {
+    sniff(data).is_some()
+}
+
+/// Extract every regular-file entry from the archive as `(name, bytes)`
+/// pairs. Directory entries are skipped.
+pub fn extract_entries(data: &[u8]) -> LibraryResult<Vec<(String, Vec<u8>)>> {
+    match sniff(data).ok_or_else(|| LibraryError::ArchiveError {
+        message: "not a recognized archive format".into(),
+    })? {
+        ArchiveKind::Zip => extract_zip(data),
+        ArchiveKind::Tar => extract_tar(std::io::Cursor::new(data)),
+        ArchiveKind::TarGz => extract_tar(flate2::read::GzDecoder::new(data)),
+        ArchiveKind::TarZst => {
+            let decoder = zstd::stream::Decoder::new(data).map_err(|e| LibraryError::Io {
+                source: e,
+            })?;
+            extract_tar(decoder)
+        }
+        ArchiveKind::TarXz => extract_tar(xz2::read::XzDecoder::new(data)),
+        ArchiveKind::TarBz2 => extract_tar(bzip2::read::BzDecoder::new(data)),
+    }
+}
+
+fn extract_zip(data: &[u8]) -> LibraryResult<Vec<(String, Vec<u8>)>> {
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(data)).map_err(|e| LibraryError::ArchiveError {
+            message: format!("open zip: {e}"),
+        })?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive
+            .by_index(i)
+            .map_err(|e| LibraryError::ArchiveError {
+                message: format!("read zip entry {i}: {e}"),
+            })?;
+        if !file.is_file() {
+            continue;
+        }
+        let name = file.name().to_string();
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)
+            .map_err(|e| LibraryError::Io { source: e })?;
+        entries.push((name, buf));
+    }
+    Ok(entries)
+}
+
+fn extract_tar<R: Read>(reader: R) -> LibraryResult<Vec<(String, Vec<u8>)>> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = Vec::new();
+    for entry in archive
+        .entries()
+        .map_err(|e| LibraryError::Io { source: e })?
+    {
+        let mut entry = entry.map_err(|e| LibraryError::Io { source: e })?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry
+            .path()
+            .map_err(|e| LibraryError::Io { source: e })?
+            .display()
+            .to_string();
+        let mut buf = Vec::new();
+        entry
+            .read_to_end(&mut buf)
+            .map_err(|e| LibraryError::Io { source: e })?;
+        entries.push((name, buf));
+    }
+    Ok(entries)
+}
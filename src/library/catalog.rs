@@ -1,17 +1,26 @@
 //! Persistent document catalog backed by `catalog.json`.
 //!
 //! The catalog is a simple JSON file listing all ingested documents.
-//! It lives at `~/.local/share/akh-medu/library/catalog.json`.
+//! It lives at `~/.local/share/akh-medu/library/catalog.json`. A sibling
+//! `chunks.json` acts as a content-addressed store mapping each ingested
+//! chunk's BLAKE3 digest to the symbol that already embeds it, so repeated
+//! passages across documents share one VSA embedding instead of being
+//! re-embedded every time.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use crate::library::error::{LibraryError, LibraryResult};
 use crate::library::model::DocumentRecord;
+use crate::symbol::SymbolId;
 
 /// Persistent index of all documents in the library.
 pub struct LibraryCatalog {
     path: PathBuf,
     records: Vec<DocumentRecord>,
+    chunk_cas_path: PathBuf,
+    /// Chunk content digest (BLAKE3 hex) → the symbol already embedding it.
+    chunk_cas: HashMap<String, u64>,
 }
 
 impl LibraryCatalog {
@@ -21,6 +30,7 @@ impl LibraryCatalog {
     /// starts with an empty list.
     pub fn open(dir: &Path) -> LibraryResult<Self> {
         let path = dir.join("catalog.json");
+        let chunk_cas_path = dir.join("chunks.json");
 
         let records = if path.exists() {
             let data = std::fs::read_to_string(&path).map_err(|e| LibraryError::CatalogIo {
@@ -33,7 +43,24 @@ impl LibraryCatalog {
             Vec::new()
         };
 
-        Ok(Self { path, records })
+        let chunk_cas = if chunk_cas_path.exists() {
+            let data =
+                std::fs::read_to_string(&chunk_cas_path).map_err(|e| LibraryError::CatalogIo {
+                    message: format!("read {}: {e}", chunk_cas_path.display()),
+                })?;
+            serde_json::from_str(&data).map_err(|e| LibraryError::CatalogIo {
+                message: format!("parse {}: {e}", chunk_cas_path.display()),
+            })?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self {
+            path,
+            records,
+            chunk_cas_path,
+            chunk_cas,
+        })
     }
 
     /// Flush the catalog to disk.
@@ -95,6 +122,43 @@ impl LibraryCatalog {
     pub fn is_empty(&self) -> bool {
         self.records.is_empty()
     }
+
+    /// Look up a document by the BLAKE3 digest of its raw source bytes.
+    pub fn find_by_source_digest(&self, digest: &str) -> Option<&DocumentRecord> {
+        self.records.iter().find(|r| r.source_digest == digest)
+    }
+
+    /// Look up the symbol already embedding a chunk, by the BLAKE3 digest of
+    /// its normalized text.
+    pub fn find_chunk(&self, digest: &str) -> Option<SymbolId> {
+        self.chunk_cas
+            .get(digest)
+            .and_then(|&raw| SymbolId::new(raw))
+    }
+
+    /// Register a chunk's digest as already embedded by `symbol`.
+    pub fn register_chunk(&mut self, digest: String, symbol: SymbolId) -> LibraryResult<()> {
+        self.chunk_cas.insert(digest, symbol.get());
+        self.flush_chunk_cas()
+    }
+
+    /// Flush the chunk CAS index to disk.
+    fn flush_chunk_cas(&self) -> LibraryResult<()> {
+        if let Some(parent) = self.chunk_cas_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| LibraryError::CatalogIo {
+                message: format!("create dir {}: {e}", parent.display()),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&self.chunk_cas).map_err(|e| {
+            LibraryError::CatalogIo {
+                message: format!("serialize chunk cas: {e}"),
+            }
+        })?;
+        std::fs::write(&self.chunk_cas_path, json).map_err(|e| LibraryError::CatalogIo {
+            message: format!("write {}: {e}", self.chunk_cas_path.display()),
+        })?;
+        Ok(())
+    }
 }
 
 /// Generate a URL-safe slug from a title string.
@@ -140,6 +204,11 @@ mod tests {
             chunk_count: 5,
             triple_count: 10,
             ingested_at: 0,
+            source_digest: "digest-test-doc".into(),
+            document_symbol: 1,
+            source_crc32: 0,
+            source_sha256: String::new(),
+            chunk_records: vec![],
         };
         catalog.add(record).unwrap();
         assert_eq!(catalog.len(), 1);
@@ -160,6 +229,11 @@ mod tests {
             chunk_count: 0,
             triple_count: 0,
             ingested_at: 0,
+            source_digest: "digest-dup".into(),
+            document_symbol: 2,
+            source_crc32: 0,
+            source_sha256: String::new(),
+            chunk_records: vec![],
         };
         catalog.add(record.clone()).unwrap();
         let err = catalog.add(record).unwrap_err();
@@ -180,6 +254,11 @@ mod tests {
             chunk_count: 0,
             triple_count: 0,
             ingested_at: 0,
+            source_digest: "digest-removable".into(),
+            document_symbol: 3,
+            source_crc32: 0,
+            source_sha256: String::new(),
+            chunk_records: vec![],
         };
         catalog.add(record).unwrap();
         assert_eq!(catalog.len(), 1);
@@ -204,6 +283,11 @@ mod tests {
                 chunk_count: 3,
                 triple_count: 7,
                 ingested_at: 1234567890,
+                source_digest: "digest-persistent".into(),
+                document_symbol: 4,
+                source_crc32: 0,
+                source_sha256: String::new(),
+                chunk_records: vec![],
             };
             catalog.add(record).unwrap();
         }
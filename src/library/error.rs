@@ -100,6 +100,16 @@ pub enum LibraryError {
         #[source]
         source: std::io::Error,
     },
+
+    #[error("archive error: {message}")]
+    #[diagnostic(
+        code(akh::library::archive_error),
+        help(
+            "Failed to read the archive. Verify it is a valid zip/tar archive \
+             and not corrupted or using an unrecognized compression codec."
+        )
+    )]
+    ArchiveError { message: String },
 }
 
 /// Convenience alias for library operation results.
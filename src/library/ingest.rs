@@ -7,6 +7,7 @@ use std::path::Path;
 
 use crate::engine::Engine;
 use crate::graph::Triple;
+use crate::library::archive;
 use crate::library::catalog::{LibraryCatalog, slugify};
 use crate::library::chunker::{ChunkConfig, normalize_chunks};
 use crate::library::error::{LibraryError, LibraryResult};
@@ -16,6 +17,7 @@ use crate::library::predicates::LibraryPredicates;
 use crate::provenance::{DerivationKind, ProvenanceRecord};
 use crate::symbol::SymbolId;
 use crate::vsa::encode::encode_label;
+use sha2::{Digest, Sha256};
 
 /// Configuration for the ingestion pipeline.
 pub struct IngestConfig {
@@ -27,6 +29,9 @@ pub struct IngestConfig {
     pub format: Option<ContentFormat>,
     /// Chunk normalization settings.
     pub chunk_config: ChunkConfig,
+    /// Skip re-ingesting unchanged documents and re-embedding chunks that
+    /// already exist in the catalog's content-addressed store.
+    pub dedup: bool,
 }
 
 impl Default for IngestConfig {
@@ -36,6 +41,7 @@ impl Default for IngestConfig {
             tags: Vec::new(),
             format: None,
             chunk_config: ChunkConfig::default(),
+            dedup: true,
         }
     }
 }
@@ -50,6 +56,8 @@ pub struct IngestResult {
     pub triple_count: usize,
     /// Total chunks after normalization.
     pub chunk_count: usize,
+    /// Chunks that reused an existing embedding instead of being re-embedded.
+    pub chunks_deduped: usize,
 }
 
 /// Ingest a document from raw bytes with a known source.
@@ -70,6 +78,28 @@ pub fn ingest_document(
     source: DocumentSource,
     config: IngestConfig,
 ) -> LibraryResult<IngestResult> {
+    // 0. Short-circuit unchanged re-ingests by the digest of the raw bytes.
+    let source_digest = blake3::hash(data).to_hex().to_string();
+    let source_crc32 = crc32fast::hash(data);
+    let source_sha256 = format!("{:x}", Sha256::digest(data));
+    if config.dedup {
+        if let Some(existing) = catalog.find_by_source_digest(&source_digest) {
+            let document_symbol = SymbolId::new(existing.document_symbol).ok_or_else(|| {
+                LibraryError::IngestFailed {
+                    document: existing.id.clone(),
+                    message: "catalog record has an invalid document symbol".into(),
+                }
+            })?;
+            return Ok(IngestResult {
+                triple_count: existing.triple_count,
+                chunk_count: existing.chunk_count,
+                chunks_deduped: existing.chunk_count,
+                record: existing.clone(),
+                document_symbol,
+            });
+        }
+    }
+
     // 1. Determine format.
     let format = config.format.unwrap_or_else(|| {
         let source_str = source.to_string();
@@ -161,10 +191,42 @@ pub fn ingest_document(
 
     // 8. Per-chunk: create paragraph symbols, structural triples, NLP extraction, VSA.
     let mut prev_chunk_sym: Option<SymbolId> = None;
+    let mut chunks_deduped = 0usize;
+    let mut chunk_records: Vec<ChunkIntegrity> = Vec::new();
 
     for chunk in &chunks {
-        let para_label = format!("para:{slug}:{}", chunk.index);
-        let para_sym = create_entity(engine, &para_label, &slug)?;
+        let normalized_text = normalize_chunk_text(&chunk.text);
+        let chunk_digest = blake3::hash(normalized_text.as_bytes()).to_hex().to_string();
+        let known_sym = if config.dedup {
+            catalog.find_chunk(&chunk_digest)
+        } else {
+            None
+        };
+
+        let para_sym = if let Some(sym) = known_sym {
+            chunks_deduped += 1;
+            sym
+        } else {
+            let para_label = format!("para:{slug}:{}", chunk.index);
+            let sym = create_entity(engine, &para_label, &slug)?;
+
+            // NLP extraction: run regex-based triple extraction on chunk text.
+            triple_count += run_nlp_extraction(engine, &chunk.text, &slug)?;
+
+            // VSA embedding: encode the chunk text and insert into item memory.
+            if let Ok(vec) = encode_label(engine.ops(), &chunk.text) {
+                engine.item_memory().insert(sym, vec);
+            }
+
+            catalog.register_chunk(chunk_digest, sym)?;
+            sym
+        };
+
+        chunk_records.push(ChunkIntegrity {
+            symbol: para_sym.get(),
+            crc32: crc32fast::hash(normalized_text.as_bytes()),
+            sha256: format!("{:x}", Sha256::digest(normalized_text.as_bytes())),
+        });
 
         // Link to document.
         add_triple(engine, doc_sym, preds.has_paragraph, para_sym, &slug)?;
@@ -189,14 +251,6 @@ pub fn ingest_document(
         add_triple(engine, para_sym, preds.chunk_index, idx_sym, &slug)?;
         triple_count += 1;
 
-        // NLP extraction: run regex-based triple extraction on chunk text.
-        triple_count += run_nlp_extraction(engine, &chunk.text, &slug)?;
-
-        // VSA embedding: encode the chunk text and insert into item memory.
-        if let Ok(vec) = encode_label(engine.ops(), &chunk.text) {
-            engine.item_memory().insert(para_sym, vec);
-        }
-
         // Provenance: record document ingestion origin.
         store_provenance(engine, para_sym, &slug, format, chunk.index as u32);
     }
@@ -216,6 +270,11 @@ pub fn ingest_document(
         chunk_count: chunks.len(),
         triple_count,
         ingested_at: now,
+        source_digest,
+        document_symbol: doc_sym.get(),
+        source_crc32,
+        source_sha256,
+        chunk_records,
     };
     catalog.add(record.clone())?;
 
@@ -224,6 +283,7 @@ pub fn ingest_document(
         document_symbol: doc_sym,
         triple_count,
         chunk_count: chunks.len(),
+        chunks_deduped,
     })
 }
 
@@ -234,10 +294,165 @@ pub fn ingest_file(
     path: &Path,
     config: IngestConfig,
 ) -> LibraryResult<IngestResult> {
+    let (data, source, config) = read_file(path, config)?;
+    ingest_document(engine, catalog, &data, source, config)
+}
+
+/// Ingest a document from a URL via HTTP GET.
+pub fn ingest_url(
+    engine: &Engine,
+    catalog: &mut LibraryCatalog,
+    url: &str,
+    config: IngestConfig,
+) -> LibraryResult<IngestResult> {
+    let (data, source, config) = fetch_url(url, config)?;
+    ingest_document(engine, catalog, &data, source, config)
+}
+
+/// Ingest a document or archive from a filesystem path, automatically
+/// detecting archive containers (zip, tar, tar.gz, tar.zst, tar.bz2) from
+/// their leading bytes rather than trusting the file extension.
+pub fn ingest_file_auto(
+    engine: &Engine,
+    catalog: &mut LibraryCatalog,
+    path: &Path,
+    config: IngestConfig,
+) -> LibraryResult<IngestOutcome> {
+    let (data, source, config) = read_file(path, config)?;
+    ingest_auto(engine, catalog, &data, source, config)
+}
+
+/// Ingest a document or archive from a URL, automatically detecting archive
+/// containers from their leading bytes rather than trusting the URL
+/// extension or Content-Type.
+pub fn ingest_url_auto(
+    engine: &Engine,
+    catalog: &mut LibraryCatalog,
+    url: &str,
+    config: IngestConfig,
+) -> LibraryResult<IngestOutcome> {
+    let (data, source, config) = fetch_url(url, config)?;
+    ingest_auto(engine, catalog, &data, source, config)
+}
+
+/// Outcome of auto-detected ingestion: either a single document, or a
+/// collection unpacked from an archive.
+pub enum IngestOutcome {
+    Document(IngestResult),
+    Archive(ArchiveIngestResult),
+}
+
+fn ingest_auto(
+    engine: &Engine,
+    catalog: &mut LibraryCatalog,
+    data: &[u8],
+    source: DocumentSource,
+    config: IngestConfig,
+) -> LibraryResult<IngestOutcome> {
+    if archive::is_archive(data) {
+        ingest_archive(engine, catalog, data, source, config).map(IngestOutcome::Archive)
+    } else {
+        ingest_document(engine, catalog, data, source, config).map(IngestOutcome::Document)
+    }
+}
+
+/// Result of ingesting an archive: one child [`IngestResult`] per
+/// successfully ingested entry, plus counts for entries that were skipped
+/// (unsupported format) or failed outright.
+pub struct ArchiveIngestResult {
+    /// Symbol for the parent "collection" document representing the archive.
+    pub collection_symbol: SymbolId,
+    /// Successfully ingested entries.
+    pub ingested: Vec<IngestResult>,
+    /// Entries skipped because their format couldn't be detected.
+    pub skipped: usize,
+    /// Entries that failed during ingestion.
+    pub failed: usize,
+}
+
+/// Unpack an archive and ingest each contained document, linking them all to
+/// a parent "collection" symbol so the knowledge graph records the
+/// archive's structure. Entries with an unsupported format are skipped
+/// rather than aborting the whole archive.
+fn ingest_archive(
+    engine: &Engine,
+    catalog: &mut LibraryCatalog,
+    data: &[u8],
+    source: DocumentSource,
+    config: IngestConfig,
+) -> LibraryResult<ArchiveIngestResult> {
+    let entries = archive::extract_entries(data)?;
+
+    let preds = LibraryPredicates::init(engine).map_err(|e| LibraryError::IngestFailed {
+        document: source.to_string(),
+        message: format!("predicate init: {e}"),
+    })?;
+
+    let collection_label = config
+        .title
+        .clone()
+        .unwrap_or_else(|| source.to_string());
+    let collection_slug = slugify(&collection_label);
+    let collection_symbol = create_entity(engine, &collection_label, &collection_slug)?;
+
+    let mut ingested = Vec::new();
+    let mut skipped = 0usize;
+    let mut failed = 0usize;
+
+    for (name, bytes) in entries {
+        let Some(format) = parser::detect_format(&name) else {
+            skipped += 1;
+            continue;
+        };
+
+        let entry_config = IngestConfig {
+            title: None,
+            tags: config.tags.clone(),
+            format: Some(format),
+            chunk_config: ChunkConfig {
+                min_words: config.chunk_config.min_words,
+                target_words: config.chunk_config.target_words,
+                max_words: config.chunk_config.max_words,
+            },
+            dedup: config.dedup,
+        };
+        let entry_source = DocumentSource::ArchiveEntry {
+            archive: Box::new(source.clone()),
+            entry: name.clone(),
+        };
+
+        match ingest_document(engine, catalog, &bytes, entry_source, entry_config) {
+            Ok(res) => {
+                add_triple(
+                    engine,
+                    collection_symbol,
+                    preds.has_member,
+                    res.document_symbol,
+                    &collection_slug,
+                )?;
+                ingested.push(res);
+            }
+            Err(_) => failed += 1,
+        }
+    }
+
+    Ok(ArchiveIngestResult {
+        collection_symbol,
+        ingested,
+        skipped,
+        failed,
+    })
+}
+
+/// Read a file from disk, deriving its [`DocumentSource`] and falling back
+/// to extension-based format detection if `config.format` isn't already set.
+fn read_file(
+    path: &Path,
+    config: IngestConfig,
+) -> LibraryResult<(Vec<u8>, DocumentSource, IngestConfig)> {
     let data = std::fs::read(path).map_err(|e| LibraryError::Io { source: e })?;
     let source = DocumentSource::File(path.display().to_string());
 
-    // Override format from extension if not explicitly set.
     let config = if config.format.is_none() {
         IngestConfig {
             format: parser::detect_format(&path.display().to_string()),
@@ -247,16 +462,16 @@ pub fn ingest_file(
         config
     };
 
-    ingest_document(engine, catalog, &data, source, config)
+    Ok((data, source, config))
 }
 
-/// Ingest a document from a URL via HTTP GET.
-pub fn ingest_url(
-    engine: &Engine,
-    catalog: &mut LibraryCatalog,
+/// Fetch a URL's body, deriving its [`DocumentSource`] and falling back to
+/// Content-Type- and extension-based format detection if `config.format`
+/// isn't already set.
+fn fetch_url(
     url: &str,
     config: IngestConfig,
-) -> LibraryResult<IngestResult> {
+) -> LibraryResult<(Vec<u8>, DocumentSource, IngestConfig)> {
     let response = ureq::get(url)
         .call()
         .map_err(|e| LibraryError::FetchError {
@@ -288,7 +503,7 @@ pub fn ingest_url(
         config
     };
 
-    ingest_document(engine, catalog, &data, source, config)
+    Ok((data, source, config))
 }
 
 // ---------------------------------------------------------------------------
@@ -408,6 +623,19 @@ fn run_nlp_extraction(engine: &Engine, text: &str, slug: &str) -> LibraryResult<
     Ok(count)
 }
 
+/// Normalize chunk text for content-addressed dedup: collapse whitespace and
+/// lowercase, so re-wrapped or re-cased copies of the same passage hash the
+/// same.
+///
+/// `pub(crate)` so `library_verify` can re-derive the same canonicalized
+/// text from a re-parsed source to recheck [`ChunkIntegrity`] digests.
+pub(crate) fn normalize_chunk_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 /// Store a provenance record for a derived chunk symbol.
 fn store_provenance(
     engine: &Engine,
@@ -7,6 +7,7 @@
 //! Each document gets its own compartment (`library:{slug}`) that can be
 //! mounted by any workspace.
 
+pub mod archive;
 pub mod catalog;
 pub mod chunker;
 pub mod error;
@@ -18,7 +19,10 @@ pub mod predicates;
 
 pub use catalog::LibraryCatalog;
 pub use error::{LibraryError, LibraryResult};
-pub use ingest::{IngestConfig, IngestResult, ingest_document, ingest_file, ingest_url};
+pub use ingest::{
+    ArchiveIngestResult, IngestConfig, IngestOutcome, IngestResult, ingest_document, ingest_file,
+    ingest_file_auto, ingest_url, ingest_url_auto,
+};
 pub use model::{ContentFormat, DocumentRecord, DocumentSource};
 pub use predicates::LibraryPredicates;
 
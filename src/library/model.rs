@@ -42,6 +42,14 @@ pub enum DocumentSource {
     Url(String),
     /// Inline text (e.g., pasted into chat).
     Inline,
+    /// A document unpacked from within an archive. `archive` is the
+    /// archive's own source, so re-verification can re-read and re-extract
+    /// it when that source is itself a local file; `entry` is this
+    /// document's path inside the archive.
+    ArchiveEntry {
+        archive: Box<DocumentSource>,
+        entry: String,
+    },
 }
 
 impl std::fmt::Display for DocumentSource {
@@ -50,6 +58,7 @@ impl std::fmt::Display for DocumentSource {
             Self::File(path) => write!(f, "file:{path}"),
             Self::Url(url) => write!(f, "{url}"),
             Self::Inline => write!(f, "(inline)"),
+            Self::ArchiveEntry { archive, entry } => write!(f, "{archive}::{entry}"),
         }
     }
 }
@@ -73,6 +82,33 @@ pub struct DocumentRecord {
     pub triple_count: usize,
     /// Timestamp of ingestion (seconds since UNIX epoch).
     pub ingested_at: u64,
+    /// BLAKE3 digest (hex) of the raw source bytes, used to detect an
+    /// unchanged re-ingest of the same file or URL without re-parsing it.
+    pub source_digest: String,
+    /// The document's root symbol ID in the KG, as a raw `u64` (JSON-friendly).
+    pub document_symbol: u64,
+    /// CRC32 of the raw source bytes, for a cheap first-pass corruption check.
+    pub source_crc32: u32,
+    /// SHA-256 (hex) of the raw source bytes, for a strong corruption check.
+    pub source_sha256: String,
+    /// Integrity digests for each chunk, recorded at ingest time.
+    /// `library_verify` re-parses the source (when it can be resolved) and
+    /// compares the recomputed chunk-text digests against these; a chunk's
+    /// embedding is only checked for presence in item memory.
+    pub chunk_records: Vec<ChunkIntegrity>,
+}
+
+/// CRC32 + SHA-256 of a single chunk's canonicalized text, taken at ingest
+/// time so the chunk text re-parsed from the source can be checked for
+/// silent corruption later, without keeping the chunk text itself around.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkIntegrity {
+    /// The chunk's symbol ID in the KG, as a raw `u64` (JSON-friendly).
+    pub symbol: u64,
+    /// CRC32 of the chunk's canonicalized text.
+    pub crc32: u32,
+    /// SHA-256 (hex) of the chunk's canonicalized text.
+    pub sha256: String,
 }
 
 /// Metadata extracted from the document during parsing.
@@ -17,6 +17,8 @@ pub struct LibraryPredicates {
     pub has_section: SymbolId,
     pub has_paragraph: SymbolId,
     pub next_chunk: SymbolId,
+    /// Links an archive's "collection" document to each document it contains.
+    pub has_member: SymbolId,
 
     // Metadata relations
     pub has_title: SymbolId,
@@ -34,13 +36,14 @@ pub struct LibraryPredicates {
 }
 
 impl LibraryPredicates {
-    /// Resolve or create all 14 well-known doc predicates in the engine.
+    /// Resolve or create all 15 well-known doc predicates in the engine.
     pub fn init(engine: &Engine) -> AkhResult<Self> {
         Ok(Self {
             has_chapter: engine.resolve_or_create_relation("doc:has_chapter")?,
             has_section: engine.resolve_or_create_relation("doc:has_section")?,
             has_paragraph: engine.resolve_or_create_relation("doc:has_paragraph")?,
             next_chunk: engine.resolve_or_create_relation("doc:next_chunk")?,
+            has_member: engine.resolve_or_create_relation("doc:has_member")?,
             has_title: engine.resolve_or_create_relation("doc:has_title")?,
             has_author: engine.resolve_or_create_relation("doc:has_author")?,
             has_format: engine.resolve_or_create_relation("doc:has_format")?,
@@ -1703,15 +1703,15 @@ fn main() -> Result<()> {
                 PipelineAction::List => {
                     println!("Built-in pipelines:");
                     println!();
-                    let query = Pipeline::query_pipeline();
-                    println!("  \"{}\" - {} stages:", query.name, query.stages.len());
-                    for (i, stage) in query.stages.iter().enumerate() {
+                    let query = Pipeline::query_pipeline_stages();
+                    println!("  \"query\" - {} stages:", query.len());
+                    for (i, stage) in query.iter().enumerate() {
                         println!("    [{}] {} ({:?})", i + 1, stage.name, stage.kind);
                     }
                     println!();
-                    let ingest = Pipeline::ingest_pipeline();
-                    println!("  \"{}\" - {} stage(s):", ingest.name, ingest.stages.len());
-                    for (i, stage) in ingest.stages.iter().enumerate() {
+                    let ingest = Pipeline::ingest_pipeline_stages();
+                    println!("  \"ingest\" - {} stage(s):", ingest.len());
+                    for (i, stage) in ingest.iter().enumerate() {
                         println!("    [{}] {} ({:?})", i + 1, stage.name, stage.kind);
                     }
                 }
@@ -1727,9 +1727,9 @@ fn main() -> Result<()> {
                         .collect();
                     let seed_ids = seed_ids.into_diagnostic()?;
 
-                    let mut pipeline = Pipeline::query_pipeline();
+                    let mut stages = Pipeline::query_pipeline_stages();
                     // Apply custom config to retrieve stage.
-                    if let Some(stage) = pipeline.stages.first_mut() {
+                    if let Some(stage) = stages.first_mut() {
                         stage.config = StageConfig::Retrieve {
                             traversal: TraversalConfig {
                                 max_depth,
@@ -1738,7 +1738,7 @@ fn main() -> Result<()> {
                         };
                     }
                     // Apply custom config to infer stage.
-                    if let Some(stage) = pipeline.stages.get_mut(1) {
+                    if let Some(stage) = stages.get_mut(1) {
                         stage.config = StageConfig::Infer {
                             query_template: InferenceQuery {
                                 max_depth: infer_depth,
@@ -1746,6 +1746,7 @@ fn main() -> Result<()> {
                             },
                         };
                     }
+                    let pipeline = Pipeline::from_stages("query", stages);
 
                     let output = engine
                         .run_pipeline(&pipeline, PipelineData::Seeds(seed_ids))
@@ -1790,10 +1791,7 @@ fn main() -> Result<()> {
                         })
                         .collect();
 
-                    let pipeline = Pipeline {
-                        name: "custom".into(),
-                        stages: stage_list,
-                    };
+                    let pipeline = Pipeline::from_stages("custom", stage_list);
 
                     let output = engine
                         .run_pipeline(&pipeline, PipelineData::Seeds(seed_ids))
@@ -3695,8 +3693,14 @@ fn format_pipeline_data_summary(
         }
         PipelineData::Reasoning(result) => {
             format!(
-                "\"{}\" (cost: {}, saturated: {})",
-                result.simplified_expr, result.cost, result.saturated
+                "\"{}\" (cost: {}, saturated: {}, {} alternative(s), {} e-classes/{} e-nodes over {} iteration(s))",
+                result.simplified_expr,
+                result.cost,
+                result.saturated,
+                result.alternatives.len(),
+                result.eclasses,
+                result.enodes,
+                result.iterations
             )
         }
     }
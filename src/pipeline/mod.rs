@@ -3,12 +3,20 @@
 //! Pipelines chain together processing stages (extract, infer, reason, retrieve)
 //! in a linear sequence. Each stage consumes the output of the previous stage
 //! and produces data for the next.
-
+//!
+//! Stages are pluggable: implement [`Stage`] to add a domain-specific stage
+//! (dedup, embedding lookup, external tool call) without editing this crate.
+//! The four built-in stages below are ordinary `Stage` impls, constructed
+//! from a [`StageKind`]/[`StageConfig`] pair for callers (e.g. the CLI) that
+//! want to describe a pipeline declaratively before building it.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use egg::{AstSize, Extractor, Rewrite, Runner};
+use egg::{AstDepth, AstSize, CostFunction, Extractor, Language, Rewrite, Runner};
 
-use crate::error::PipelineError;
+use crate::error::{PipelineError, PipelineValidationError};
 use crate::graph::index::KnowledgeGraph;
 use crate::graph::traverse::{traverse_bfs, TraversalConfig, TraversalResult};
 use crate::graph::Triple;
@@ -52,6 +60,51 @@ impl PipelineData {
             Self::Reasoning(_) => "Reasoning",
         }
     }
+
+    /// The [`DataShape`] this value carries, for [`Pipeline::validate`] to
+    /// check the stage list against the data actually being run through it.
+    fn shape(&self) -> DataShape {
+        match self {
+            Self::Seeds(_) => DataShape::Seeds,
+            Self::Triples(_) => DataShape::Triples,
+            Self::Traversal(_) => DataShape::Traversal,
+            Self::Inference(_) => DataShape::Inference,
+            Self::Reasoning(_) => DataShape::Reasoning,
+        }
+    }
+}
+
+/// Abstract shape of a [`PipelineData`] value, used by [`Pipeline::validate`]
+/// to type-check a stage list without any actual data or [`PipelineContext`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataShape {
+    Seeds,
+    Triples,
+    Traversal,
+    Inference,
+    Reasoning,
+}
+
+impl std::fmt::Display for DataShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Seeds => "Seeds",
+            Self::Triples => "Triples",
+            Self::Traversal => "Traversal",
+            Self::Inference => "Inference",
+            Self::Reasoning => "Reasoning",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Render a list of accepted shapes as `"Seeds or Triples"` for error messages.
+fn describe_shapes(shapes: &[DataShape]) -> String {
+    shapes
+        .iter()
+        .map(DataShape::to_string)
+        .collect::<Vec<_>>()
+        .join(" or ")
 }
 
 /// Result of e-graph reasoning.
@@ -63,8 +116,40 @@ pub struct ReasoningResult {
     pub cost: usize,
     /// Whether the e-graph reached saturation.
     pub saturated: bool,
+    /// Other distinct expressions considered for the same root, ordered by
+    /// increasing cost. Does not include `simplified_expr` itself.
+    pub alternatives: Vec<Alternative>,
+    /// Number of e-classes in the e-graph when extraction ran.
+    pub eclasses: usize,
+    /// Number of e-nodes in the e-graph when extraction ran.
+    pub enodes: usize,
+    /// Number of iterations the runner completed before stopping.
+    pub iterations: usize,
+    /// Rewrite rules that fired at least once, with their total application
+    /// count across all iterations, sorted by rule name.
+    pub rules_applied: Vec<(String, usize)>,
 }
 
+/// A simplified expression considered during extraction, alongside its cost.
+#[derive(Debug, Clone)]
+pub struct Alternative {
+    pub expr: String,
+    pub cost: usize,
+}
+
+/// Cost function the Reason stage's extractor ranks candidate expressions by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CostFn {
+    /// Prefer the smallest expression, by node count (`egg::AstSize`).
+    #[default]
+    AstSize,
+    /// Prefer the shallowest expression, by tree depth (`egg::AstDepth`).
+    AstDepth,
+}
+
+/// Default number of distinct expressions `ReasonStage` reports (best + alternatives).
+const DEFAULT_TOP_K: usize = 3;
+
 // ---------------------------------------------------------------------------
 // Stage configuration
 // ---------------------------------------------------------------------------
@@ -80,6 +165,8 @@ pub enum StageConfig {
     Reason {
         max_iterations: usize,
         node_limit: usize,
+        cost: CostFn,
+        top_k: usize,
     },
     /// Configuration for the ExtractTriples stage.
     ExtractTriples { min_confidence: f32 },
@@ -87,7 +174,8 @@ pub enum StageConfig {
     Default,
 }
 
-/// Built-in pipeline stage types.
+/// Built-in pipeline stage kinds, used to describe a stage declaratively
+/// (e.g. from the CLI) before building it into a [`Stage`] trait object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StageKind {
     /// Extract triples from input.
@@ -100,7 +188,66 @@ pub enum StageKind {
     Retrieve,
 }
 
-/// Named processing stage in a pipeline.
+impl StageKind {
+    /// Build the concrete built-in [`Stage`] impl for this kind, applying
+    /// `config` where it matches and falling back to that stage's defaults
+    /// otherwise.
+    pub fn build(self, name: impl Into<String>, config: &StageConfig) -> Arc<dyn Stage> {
+        let name = name.into();
+        match self {
+            StageKind::Retrieve => {
+                let traversal = match config {
+                    StageConfig::Retrieve { traversal } => traversal.clone(),
+                    _ => TraversalConfig::default(),
+                };
+                Arc::new(RetrieveStage { name, traversal })
+            }
+            StageKind::Infer => {
+                let query_template = match config {
+                    StageConfig::Infer { query_template } => query_template.clone(),
+                    _ => InferenceQuery::default(),
+                };
+                Arc::new(InferStage {
+                    name,
+                    query_template,
+                })
+            }
+            StageKind::Reason => {
+                let (max_iterations, node_limit, cost, top_k) = match config {
+                    StageConfig::Reason {
+                        max_iterations,
+                        node_limit,
+                        cost,
+                        top_k,
+                    } => (*max_iterations, *node_limit, *cost, *top_k),
+                    _ => (100, 10_000, CostFn::default(), DEFAULT_TOP_K),
+                };
+                Arc::new(ReasonStage {
+                    name,
+                    max_iterations,
+                    node_limit,
+                    cost,
+                    top_k,
+                })
+            }
+            StageKind::ExtractTriples => {
+                let min_confidence = match config {
+                    StageConfig::ExtractTriples { min_confidence } => *min_confidence,
+                    _ => 0.0,
+                };
+                Arc::new(ExtractTriplesStage {
+                    name,
+                    min_confidence,
+                })
+            }
+        }
+    }
+}
+
+/// Declarative description of a pipeline stage: a name, a built-in kind, and
+/// that kind's configuration. Used to build a stage before it is boxed into
+/// the pipeline via [`StageKind::build`] (or [`PipelineStage::into_stage`]),
+/// and to introspect/reconfigure a stage (e.g. from the CLI) prior to that.
 #[derive(Debug, Clone)]
 pub struct PipelineStage {
     /// Stage name.
@@ -111,6 +258,531 @@ pub struct PipelineStage {
     pub config: StageConfig,
 }
 
+impl PipelineStage {
+    /// Build this descriptor into a concrete boxed [`Stage`].
+    pub fn into_stage(self) -> Arc<dyn Stage> {
+        self.kind.build(self.name, &self.config)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pluggable stage trait
+// ---------------------------------------------------------------------------
+
+/// A single stage in a pipeline: consumes the previous stage's output and
+/// produces the next stage's input.
+///
+/// Implement this trait to plug a custom stage into a [`Pipeline`] — e.g. a
+/// dedup stage, an embedding-lookup stage, or a call out to an external
+/// tool — without needing to extend [`StageKind`]. [`PipelineData`] remains
+/// the shared wire format between stages of any kind.
+pub trait Stage: std::fmt::Debug + Send + Sync {
+    /// Stage name, used in error messages and [`PipelineOutput::stage_results`].
+    fn name(&self) -> &str;
+
+    /// Consume `input` and produce the data for the next stage.
+    fn execute(&self, ctx: &PipelineContext, input: &PipelineData) -> PipelineResult<PipelineData>;
+
+    /// Like [`Stage::execute`], but given an interrupt flag the stage may poll
+    /// during a long-running inner loop (e.g. e-graph saturation) to abort
+    /// promptly instead of running to completion.
+    ///
+    /// The default implementation ignores `interrupt` and simply delegates to
+    /// [`Stage::execute`]; stages with no long-running inner loop don't need
+    /// to override this.
+    fn execute_interruptible(
+        &self,
+        ctx: &PipelineContext,
+        input: &PipelineData,
+        interrupt: &Arc<AtomicBool>,
+    ) -> PipelineResult<PipelineData> {
+        let _ = interrupt;
+        self.execute(ctx, input)
+    }
+
+    /// The [`DataShape`]s this stage accepts as input, for static validation
+    /// via [`Pipeline::validate`]. `None` means the stage's input contract
+    /// can't be statically described (e.g. a custom stage that inspects the
+    /// runtime variant itself); validation skips checking it.
+    fn accepts(&self) -> Option<&'static [DataShape]> {
+        None
+    }
+
+    /// The [`DataShape`] this stage produces, for static validation. `None`
+    /// means the output shape can't be statically determined, so validation
+    /// treats everything downstream as unchecked until a later stage
+    /// re-establishes a known shape.
+    fn produces(&self) -> Option<DataShape> {
+        None
+    }
+}
+
+/// [`DataShape`]s that [`extract_seeds`] can pull seed symbols from.
+const SEED_COMPATIBLE: &[DataShape] = &[
+    DataShape::Seeds,
+    DataShape::Triples,
+    DataShape::Traversal,
+    DataShape::Inference,
+];
+
+/// Extract seeds from any pipeline data variant.
+fn extract_seeds(data: &PipelineData) -> PipelineResult<Vec<SymbolId>> {
+    match data {
+        PipelineData::Seeds(seeds) => {
+            if seeds.is_empty() {
+                Err(PipelineError::NoSeeds)
+            } else {
+                Ok(seeds.clone())
+            }
+        }
+        PipelineData::Triples(triples) => {
+            let mut seeds: Vec<SymbolId> = triples
+                .iter()
+                .flat_map(|t| [t.subject, t.predicate, t.object])
+                .collect();
+            seeds.sort();
+            seeds.dedup();
+            if seeds.is_empty() {
+                Err(PipelineError::NoSeeds)
+            } else {
+                Ok(seeds)
+            }
+        }
+        PipelineData::Traversal(result) => {
+            let seeds: Vec<SymbolId> = result.visited.iter().copied().collect();
+            if seeds.is_empty() {
+                Err(PipelineError::NoSeeds)
+            } else {
+                Ok(seeds)
+            }
+        }
+        PipelineData::Inference(result) => {
+            let seeds: Vec<SymbolId> = result.activations.iter().map(|(s, _)| *s).collect();
+            if seeds.is_empty() {
+                Err(PipelineError::NoSeeds)
+            } else {
+                Ok(seeds)
+            }
+        }
+        PipelineData::Reasoning(_) => Err(PipelineError::IncompatibleData {
+            stage_name: "extract_seeds".into(),
+            expected: "Seeds, Triples, Traversal, or Inference".into(),
+            actual: "Reasoning".into(),
+        }),
+    }
+}
+
+/// Retrieve stage: BFS traversal of the knowledge graph from seed symbols.
+#[derive(Debug, Clone)]
+pub struct RetrieveStage {
+    pub name: String,
+    pub traversal: TraversalConfig,
+}
+
+impl RetrieveStage {
+    pub fn new(name: impl Into<String>, traversal: TraversalConfig) -> Self {
+        Self {
+            name: name.into(),
+            traversal,
+        }
+    }
+}
+
+impl Stage for RetrieveStage {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn execute(&self, ctx: &PipelineContext, input: &PipelineData) -> PipelineResult<PipelineData> {
+        let seeds = extract_seeds(input)?;
+
+        let result = traverse_bfs(&ctx.knowledge_graph, &seeds, &self.traversal).map_err(|e| {
+            PipelineError::StageExecution {
+                stage_name: self.name.clone(),
+                message: format!("traversal failed: {e}"),
+            }
+        })?;
+
+        Ok(PipelineData::Traversal(result))
+    }
+
+    fn accepts(&self) -> Option<&'static [DataShape]> {
+        Some(SEED_COMPATIBLE)
+    }
+
+    fn produces(&self) -> Option<DataShape> {
+        Some(DataShape::Traversal)
+    }
+}
+
+/// Infer stage: spreading-activation inference over the VSA item memory.
+#[derive(Debug, Clone)]
+pub struct InferStage {
+    pub name: String,
+    pub query_template: InferenceQuery,
+}
+
+impl InferStage {
+    pub fn new(name: impl Into<String>, query_template: InferenceQuery) -> Self {
+        Self {
+            name: name.into(),
+            query_template,
+        }
+    }
+}
+
+impl Stage for InferStage {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn execute(&self, ctx: &PipelineContext, input: &PipelineData) -> PipelineResult<PipelineData> {
+        let seeds = extract_seeds(input)?;
+
+        let mut query = self.query_template.clone();
+        query.seeds = seeds;
+
+        let engine = InferEngine::new(
+            Arc::clone(&ctx.ops),
+            Arc::clone(&ctx.item_memory),
+            Arc::clone(&ctx.knowledge_graph),
+        );
+
+        let result = engine.infer_with_rules(&query, &ctx.rules).map_err(|e| {
+            PipelineError::StageExecution {
+                stage_name: self.name.clone(),
+                message: format!("inference failed: {e}"),
+            }
+        })?;
+
+        Ok(PipelineData::Inference(result))
+    }
+
+    fn accepts(&self) -> Option<&'static [DataShape]> {
+        Some(SEED_COMPATIBLE)
+    }
+
+    fn produces(&self) -> Option<DataShape> {
+        Some(DataShape::Inference)
+    }
+}
+
+/// Reason stage: e-graph saturation and cost-based extraction via `egg`.
+#[derive(Debug, Clone)]
+pub struct ReasonStage {
+    pub name: String,
+    pub max_iterations: usize,
+    pub node_limit: usize,
+    pub cost: CostFn,
+    pub top_k: usize,
+}
+
+impl ReasonStage {
+    pub fn new(name: impl Into<String>, max_iterations: usize, node_limit: usize) -> Self {
+        Self {
+            name: name.into(),
+            max_iterations,
+            node_limit,
+            cost: CostFn::default(),
+            top_k: DEFAULT_TOP_K,
+        }
+    }
+
+    /// Rank candidate expressions by `cost` instead of the default `AstSize`.
+    pub fn with_cost(mut self, cost: CostFn) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// Report up to `top_k` distinct expressions (best + alternatives) instead
+    /// of the default of [`DEFAULT_TOP_K`].
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k;
+        self
+    }
+}
+
+impl ReasonStage {
+    /// Build the s-expression to saturate from inference activations or seeds.
+    /// Returns `Ok(None)` when the input is empty and reasoning can be skipped.
+    fn build_expr(&self, input: &PipelineData) -> PipelineResult<Option<egg::RecExpr<AkhLang>>> {
+        let expr_str = match input {
+            PipelineData::Inference(result) => {
+                if result.activations.is_empty() {
+                    return Ok(None);
+                }
+                // Build a nested bundle of the top activations.
+                let ids: Vec<String> = result
+                    .activations
+                    .iter()
+                    .take(10)
+                    .map(|(s, _)| s.get().to_string())
+                    .collect();
+                if ids.len() == 1 {
+                    ids[0].clone()
+                } else {
+                    ids.iter().skip(1).fold(ids[0].clone(), |acc, id| {
+                        format!("(bundle {} {})", acc, id)
+                    })
+                }
+            }
+            PipelineData::Seeds(seeds) => {
+                if seeds.is_empty() {
+                    return Err(PipelineError::NoSeeds);
+                }
+                let ids: Vec<String> = seeds.iter().map(|s| s.get().to_string()).collect();
+                if ids.len() == 1 {
+                    ids[0].clone()
+                } else {
+                    ids.iter().skip(1).fold(ids[0].clone(), |acc, id| {
+                        format!("(bundle {} {})", acc, id)
+                    })
+                }
+            }
+            other => {
+                return Err(PipelineError::IncompatibleData {
+                    stage_name: self.name.clone(),
+                    expected: "Inference or Seeds".into(),
+                    actual: other.variant_name().into(),
+                });
+            }
+        };
+
+        let expr = expr_str
+            .parse::<egg::RecExpr<AkhLang>>()
+            .map_err(|e| PipelineError::StageExecution {
+                stage_name: self.name.clone(),
+                message: format!("expression parse failed: {e}"),
+            })?;
+
+        Ok(Some(expr))
+    }
+
+    /// A `Reasoning` result for an empty input, skipping the e-graph entirely.
+    fn empty_result() -> PipelineData {
+        PipelineData::Reasoning(ReasoningResult {
+            simplified_expr: String::new(),
+            cost: 0,
+            saturated: true,
+            alternatives: Vec::new(),
+            eclasses: 0,
+            enodes: 0,
+            iterations: 0,
+            rules_applied: Vec::new(),
+        })
+    }
+
+    /// Extract the best expression (and up to `top_k` distinct alternatives)
+    /// from a saturated (or stopped) runner, using `self.cost` to rank them.
+    fn extract(&self, runner: egg::Runner<AkhLang, ()>) -> PipelineData {
+        match self.cost {
+            CostFn::AstSize => Self::extract_with(runner, AstSize, AstSize, self.top_k),
+            CostFn::AstDepth => Self::extract_with(runner, AstDepth, AstDepth, self.top_k),
+        }
+    }
+
+    fn extract_with<CF>(
+        runner: egg::Runner<AkhLang, ()>,
+        extractor_cost: CF,
+        alternatives_cost: CF,
+        top_k: usize,
+    ) -> PipelineData
+    where
+        CF: CostFunction<AkhLang, Cost = usize>,
+    {
+        let saturated = runner
+            .stop_reason
+            .as_ref()
+            .is_some_and(|r| matches!(r, egg::StopReason::Saturated));
+
+        let root = runner.roots[0];
+        let mut rule_counts: BTreeMap<String, usize> = BTreeMap::new();
+        for iteration in &runner.iterations {
+            for (rule, count) in &iteration.applied {
+                *rule_counts.entry(rule.to_string()).or_insert(0) += count;
+            }
+        }
+
+        let extractor = Extractor::new(&runner.egraph, extractor_cost);
+        let (cost, best) = extractor.find_best(root);
+        let alternatives =
+            Self::extract_alternatives(alternatives_cost, &extractor, &runner.egraph, root, top_k, &best);
+
+        PipelineData::Reasoning(ReasoningResult {
+            simplified_expr: best.to_string(),
+            cost,
+            saturated,
+            alternatives,
+            eclasses: runner.egraph.number_of_classes(),
+            enodes: runner.egraph.total_size(),
+            iterations: runner.iterations.len(),
+            rules_applied: rule_counts.into_iter().collect(),
+        })
+    }
+
+    /// Reconstruct up to `top_k - 1` other distinct expressions for `root`,
+    /// one per alternative top-level e-node, reusing the extractor's already
+    /// memoized best sub-expression for each child e-class.
+    fn extract_alternatives<CF>(
+        mut cost_fn: CF,
+        extractor: &Extractor<'_, CF, AkhLang, ()>,
+        egraph: &egg::EGraph<AkhLang, ()>,
+        root: egg::Id,
+        top_k: usize,
+        best: &egg::RecExpr<AkhLang>,
+    ) -> Vec<Alternative>
+    where
+        CF: CostFunction<AkhLang, Cost = usize>,
+    {
+        let best_str = best.to_string();
+        let mut candidates: Vec<(usize, String)> = egraph[root]
+            .nodes
+            .iter()
+            .map(|node| {
+                let cost = cost_fn.cost(node, |id| extractor.find_best(id).0);
+                let expr = node.join_recexprs(|id| extractor.find_best(id).1.clone());
+                (cost, expr.to_string())
+            })
+            .collect();
+        candidates.sort_by_key(|(cost, _)| *cost);
+        candidates.dedup_by(|a, b| a.1 == b.1);
+        candidates.retain(|(_, expr)| *expr != best_str);
+        candidates.truncate(top_k.saturating_sub(1));
+        candidates
+            .into_iter()
+            .map(|(cost, expr)| Alternative { expr, cost })
+            .collect()
+    }
+}
+
+impl Stage for ReasonStage {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn execute(&self, ctx: &PipelineContext, input: &PipelineData) -> PipelineResult<PipelineData> {
+        let Some(expr) = self.build_expr(input)? else {
+            return Ok(Self::empty_result());
+        };
+
+        let runner = Runner::default()
+            .with_iter_limit(self.max_iterations)
+            .with_node_limit(self.node_limit)
+            .with_expr(&expr)
+            .run(&ctx.rules);
+
+        Ok(self.extract(runner))
+    }
+
+    fn execute_interruptible(
+        &self,
+        ctx: &PipelineContext,
+        input: &PipelineData,
+        interrupt: &Arc<AtomicBool>,
+    ) -> PipelineResult<PipelineData> {
+        let Some(expr) = self.build_expr(input)? else {
+            return Ok(Self::empty_result());
+        };
+
+        let interrupt = Arc::clone(interrupt);
+        let runner = Runner::default()
+            .with_iter_limit(self.max_iterations)
+            .with_node_limit(self.node_limit)
+            .with_hook(move |_runner| {
+                if interrupt.load(Ordering::Relaxed) {
+                    Err("interrupted".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .with_expr(&expr)
+            .run(&ctx.rules);
+
+        Ok(self.extract(runner))
+    }
+
+    fn accepts(&self) -> Option<&'static [DataShape]> {
+        Some(&[DataShape::Inference, DataShape::Seeds])
+    }
+
+    fn produces(&self) -> Option<DataShape> {
+        Some(DataShape::Reasoning)
+    }
+}
+
+/// ExtractTriples stage: filter triples (or synthesize them from activations)
+/// by a minimum confidence threshold.
+#[derive(Debug, Clone)]
+pub struct ExtractTriplesStage {
+    pub name: String,
+    pub min_confidence: f32,
+}
+
+impl ExtractTriplesStage {
+    pub fn new(name: impl Into<String>, min_confidence: f32) -> Self {
+        Self {
+            name: name.into(),
+            min_confidence,
+        }
+    }
+}
+
+impl Stage for ExtractTriplesStage {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn execute(&self, _ctx: &PipelineContext, input: &PipelineData) -> PipelineResult<PipelineData> {
+        match input {
+            PipelineData::Triples(triples) => {
+                let filtered: Vec<Triple> = triples
+                    .iter()
+                    .filter(|t| t.confidence >= self.min_confidence)
+                    .cloned()
+                    .collect();
+                Ok(PipelineData::Triples(filtered))
+            }
+            PipelineData::Traversal(result) => {
+                let filtered: Vec<Triple> = result
+                    .triples
+                    .iter()
+                    .filter(|t| t.confidence >= self.min_confidence)
+                    .cloned()
+                    .collect();
+                Ok(PipelineData::Triples(filtered))
+            }
+            PipelineData::Inference(result) => {
+                // Convert activations to synthetic triples (activation → inferred_as → self)
+                // This is a simplified extraction; real use would have more domain logic.
+                let triples: Vec<Triple> = result
+                    .activations
+                    .iter()
+                    .filter(|(_, conf)| *conf >= self.min_confidence)
+                    .map(|(sym, conf)| Triple::new(*sym, *sym, *sym).with_confidence(*conf))
+                    .collect();
+                Ok(PipelineData::Triples(triples))
+            }
+            other => Err(PipelineError::IncompatibleData {
+                stage_name: self.name.clone(),
+                expected: "Triples, Traversal, or Inference".into(),
+                actual: other.variant_name().into(),
+            }),
+        }
+    }
+
+    fn accepts(&self) -> Option<&'static [DataShape]> {
+        Some(&[
+            DataShape::Triples,
+            DataShape::Traversal,
+            DataShape::Inference,
+        ])
+    }
+
+    fn produces(&self) -> Option<DataShape> {
+        Some(DataShape::Triples)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pipeline context (shared resources)
 // ---------------------------------------------------------------------------
@@ -146,18 +818,107 @@ pub struct PipelineOutput {
     pub stages_executed: usize,
 }
 
+/// Error from [`Pipeline::run_interruptible`]: the pipeline was empty or
+/// failed validation, the interrupt signal was observed, or a stage failed,
+/// before the pipeline ran to completion. `partial` carries the stages that
+/// completed beforehand.
+#[derive(Debug)]
+pub struct InterruptedPipeline {
+    pub error: PipelineError,
+    pub partial: PipelineOutput,
+}
+
+impl std::fmt::Display for InterruptedPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for InterruptedPipeline {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Pipeline
 // ---------------------------------------------------------------------------
 
-/// A linear processing pipeline.
-#[derive(Debug, Clone)]
+/// A linear processing pipeline of pluggable [`Stage`]s.
 pub struct Pipeline {
     pub name: String,
-    pub stages: Vec<PipelineStage>,
+    pub stages: Vec<Arc<dyn Stage>>,
+}
+
+impl std::fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pipeline")
+            .field("name", &self.name)
+            .field("stages", &self.stages.iter().map(|s| s.name()).collect::<Vec<_>>())
+            .finish()
+    }
 }
 
 impl Pipeline {
+    /// Build a pipeline from declarative stage descriptors, boxing each into
+    /// its concrete [`Stage`] impl.
+    pub fn from_stages(name: impl Into<String>, stages: Vec<PipelineStage>) -> Self {
+        Self {
+            name: name.into(),
+            stages: stages.into_iter().map(PipelineStage::into_stage).collect(),
+        }
+    }
+
+    /// Statically check the stage list's data flow without running anything:
+    /// models each stage's accepted and produced [`DataShape`]s, starting
+    /// from `initial_shape` (the shape of whatever [`PipelineData`] will
+    /// actually be passed to `run`), and walks the chain, collecting every
+    /// incompatibility — plus an empty-pipeline or missing-seed-source
+    /// problem — in one pass rather than stopping at the first one found.
+    ///
+    /// Stages that don't describe their input/output shapes (the default for
+    /// [`Stage::accepts`]/[`Stage::produces`]) are treated as opaque: the
+    /// check is skipped for them, and downstream shape tracking resumes only
+    /// once a later stage re-establishes a known shape.
+    pub fn validate(&self, initial_shape: DataShape) -> Result<(), Vec<PipelineValidationError>> {
+        let mut errors = Vec::new();
+
+        if self.stages.is_empty() {
+            errors.push(PipelineValidationError::EmptyPipeline {
+                name: self.name.clone(),
+            });
+            return Err(errors);
+        }
+
+        let mut current = Some(initial_shape);
+        for (i, stage) in self.stages.iter().enumerate() {
+            if let (Some(accepted), Some(shape)) = (stage.accepts(), current) {
+                if !accepted.contains(&shape) {
+                    if i == 0 && shape == DataShape::Seeds {
+                        errors.push(PipelineValidationError::MissingSeedSource {
+                            stage_index: i,
+                            stage_name: stage.name().to_string(),
+                        });
+                    } else {
+                        errors.push(PipelineValidationError::IncompatibleStage {
+                            stage_index: i,
+                            stage_name: stage.name().to_string(),
+                            expected: describe_shapes(accepted),
+                            actual: shape.to_string(),
+                        });
+                    }
+                }
+            }
+            current = stage.produces();
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Run the pipeline with the given context and initial data.
     pub fn run(&self, ctx: &PipelineContext, initial: PipelineData) -> PipelineResult<PipelineOutput> {
         if self.stages.is_empty() {
@@ -165,20 +926,124 @@ impl Pipeline {
                 name: self.name.clone(),
             });
         }
+        if let Err(errors) = self.validate(initial.shape()) {
+            return Err(PipelineError::ValidationFailed {
+                name: self.name.clone(),
+                errors: errors.iter().map(ToString::to_string).collect(),
+            });
+        }
+
+        self.to_graph().run(ctx, initial)
+    }
+
+    /// Lower this linear pipeline to a [`PipelineGraph`]: a straight chain
+    /// where each stage's only input edge is the previous stage (the first
+    /// stage has none, and receives the graph's initial data).
+    fn to_graph(&self) -> PipelineGraph {
+        let mut graph = PipelineGraph::new(self.name.clone());
+        let mut prev = None;
+        for stage in &self.stages {
+            let inputs = prev.into_iter().collect();
+            let id = graph.add_stage(stage.name().to_string(), Arc::clone(stage), inputs);
+            prev = Some(id);
+        }
+        if let Some(last) = prev {
+            graph.set_output(last);
+        }
+        graph
+    }
+
+    /// Run the pipeline, checking `interrupt` between stages and passing it
+    /// into stages (e.g. the `Reason` stage's `egg::Runner`) that can poll it
+    /// during a long-running inner loop.
+    ///
+    /// On cancellation or stage failure, the returned [`InterruptedPipeline`]
+    /// carries whichever stages completed before the abort, so a caller
+    /// driving this from behind a REPL or request handler can still inspect
+    /// partial progress.
+    pub fn run_interruptible(
+        &self,
+        ctx: &PipelineContext,
+        initial: PipelineData,
+        interrupt: Arc<AtomicBool>,
+    ) -> Result<PipelineOutput, InterruptedPipeline> {
+        if self.stages.is_empty() {
+            return Err(InterruptedPipeline {
+                error: PipelineError::EmptyPipeline {
+                    name: self.name.clone(),
+                },
+                partial: PipelineOutput {
+                    result: initial,
+                    stage_results: Vec::new(),
+                    stages_executed: 0,
+                },
+            });
+        }
+        if let Err(errors) = self.validate(initial.shape()) {
+            return Err(InterruptedPipeline {
+                error: PipelineError::ValidationFailed {
+                    name: self.name.clone(),
+                    errors: errors.iter().map(ToString::to_string).collect(),
+                },
+                partial: PipelineOutput {
+                    result: initial,
+                    stage_results: Vec::new(),
+                    stages_executed: 0,
+                },
+            });
+        }
 
         let mut current = initial;
         let mut stage_results = Vec::with_capacity(self.stages.len());
 
         for (i, stage) in self.stages.iter().enumerate() {
-            let output = execute_stage(ctx, &current, stage).map_err(|e| {
-                PipelineError::StageFailure {
-                    pipeline_name: self.name.clone(),
-                    stage_name: stage.name.clone(),
-                    stage_index: i,
-                    source: Box::new(e),
+            if interrupt.load(Ordering::Relaxed) {
+                return Err(InterruptedPipeline {
+                    error: PipelineError::Interrupted {
+                        stage_name: stage.name().to_string(),
+                        stage_index: i,
+                    },
+                    partial: PipelineOutput {
+                        result: current,
+                        stage_results,
+                        stages_executed: i,
+                    },
+                });
+            }
+
+            let output = match stage.execute_interruptible(ctx, &current, &interrupt) {
+                Ok(output) => output,
+                Err(e) => {
+                    return Err(InterruptedPipeline {
+                        error: PipelineError::StageFailure {
+                            pipeline_name: self.name.clone(),
+                            stage_name: stage.name().to_string(),
+                            stage_index: i,
+                            source: Box::new(e),
+                        },
+                        partial: PipelineOutput {
+                            result: current,
+                            stage_results,
+                            stages_executed: i,
+                        },
+                    });
                 }
-            })?;
-            stage_results.push((stage.name.clone(), output.clone()));
+            };
+            // A stage that polls `interrupt` (e.g. `Reason`) returns truncated output.
+            if interrupt.load(Ordering::Relaxed) {
+                return Err(InterruptedPipeline {
+                    error: PipelineError::Interrupted {
+                        stage_name: stage.name().to_string(),
+                        stage_index: i,
+                    },
+                    partial: PipelineOutput {
+                        result: current,
+                        stage_results,
+                        stages_executed: i,
+                    },
+                });
+            }
+            stage_results.push((stage.name().to_string(), output.clone()));
             current = output;
         }
 
@@ -189,301 +1054,318 @@ impl Pipeline {
         })
     }
 
-    /// Built-in query pipeline: Retrieve → Infer → Reason.
-    pub fn query_pipeline() -> Self {
-        Self {
-            name: "query".into(),
-            stages: vec![
-                PipelineStage {
-                    name: "retrieve".into(),
-                    kind: StageKind::Retrieve,
-                    config: StageConfig::Retrieve {
-                        traversal: TraversalConfig::default(),
-                    },
+    /// Declarative descriptors for the built-in query pipeline: Retrieve → Infer → Reason.
+    pub fn query_pipeline_stages() -> Vec<PipelineStage> {
+        vec![
+            PipelineStage {
+                name: "retrieve".into(),
+                kind: StageKind::Retrieve,
+                config: StageConfig::Retrieve {
+                    traversal: TraversalConfig::default(),
                 },
-                PipelineStage {
-                    name: "infer".into(),
-                    kind: StageKind::Infer,
-                    config: StageConfig::Infer {
-                        query_template: InferenceQuery::default(),
-                    },
+            },
+            PipelineStage {
+                name: "infer".into(),
+                kind: StageKind::Infer,
+                config: StageConfig::Infer {
+                    query_template: InferenceQuery::default(),
                 },
-                PipelineStage {
-                    name: "reason".into(),
-                    kind: StageKind::Reason,
-                    config: StageConfig::Reason {
-                        max_iterations: 100,
-                        node_limit: 10_000,
-                    },
+            },
+            PipelineStage {
+                name: "reason".into(),
+                kind: StageKind::Reason,
+                config: StageConfig::Reason {
+                    max_iterations: 100,
+                    node_limit: 10_000,
+                    cost: CostFn::default(),
+                    top_k: DEFAULT_TOP_K,
                 },
-            ],
-        }
+            },
+        ]
+    }
+
+    /// Built-in query pipeline: Retrieve → Infer → Reason.
+    pub fn query_pipeline() -> Self {
+        Self::from_stages("query", Self::query_pipeline_stages())
+    }
+
+    /// Declarative descriptors for the built-in ingest pipeline: ExtractTriples (single stage).
+    pub fn ingest_pipeline_stages() -> Vec<PipelineStage> {
+        vec![PipelineStage {
+            name: "extract_triples".into(),
+            kind: StageKind::ExtractTriples,
+            config: StageConfig::ExtractTriples {
+                min_confidence: 0.0,
+            },
+        }]
     }
 
     /// Built-in ingest pipeline: ExtractTriples (single stage).
     pub fn ingest_pipeline() -> Self {
+        Self::from_stages("ingest", Self::ingest_pipeline_stages())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Non-linear (DAG) pipelines
+// ---------------------------------------------------------------------------
+
+/// Identifies a node within a [`PipelineGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+/// What a [`PipelineGraph`] node does with its upstream inputs.
+enum GraphNodeKind {
+    /// Runs a [`Stage`] on its single input edge (the graph's initial data,
+    /// if the node has no input edges).
+    Stage(Arc<dyn Stage>),
+    /// Combines every input edge's output into one [`PipelineData`]: seeds
+    /// and triples are unioned, activations are concatenated. Requires at
+    /// least one input edge.
+    Merge,
+}
+
+struct GraphNode {
+    name: String,
+    kind: GraphNodeKind,
+    inputs: Vec<NodeId>,
+}
+
+/// A non-linear processing pipeline: stages are nodes with explicit input
+/// edges, so a query plan can fan out (run several stages on the same
+/// upstream data) and fan back in (a [`Merge`](GraphNodeKind::Merge) node
+/// combining their outputs) — something a linear [`Pipeline`] can't express.
+///
+/// Execution is topological: a node runs once every node it depends on has
+/// produced its output. [`Pipeline`] is a thin wrapper that lowers to a
+/// straight chain in this graph.
+pub struct PipelineGraph {
+    name: String,
+    nodes: Vec<GraphNode>,
+    output: Option<NodeId>,
+}
+
+impl PipelineGraph {
+    /// Create an empty graph.
+    pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name: "ingest".into(),
-            stages: vec![PipelineStage {
-                name: "extract_triples".into(),
-                kind: StageKind::ExtractTriples,
-                config: StageConfig::ExtractTriples {
-                    min_confidence: 0.0,
-                },
-            }],
+            name: name.into(),
+            nodes: Vec::new(),
+            output: None,
         }
     }
-}
 
-// ---------------------------------------------------------------------------
-// Stage execution
-// ---------------------------------------------------------------------------
+    /// Add a node that runs `stage` on the output of `inputs[0]` (or on the
+    /// graph's initial data, if `inputs` is empty). Returns the new node's id.
+    pub fn add_stage(
+        &mut self,
+        name: impl Into<String>,
+        stage: Arc<dyn Stage>,
+        inputs: Vec<NodeId>,
+    ) -> NodeId {
+        self.push_node(name.into(), GraphNodeKind::Stage(stage), inputs)
+    }
+
+    /// Add a node that merges the outputs of all of `inputs`. Returns the
+    /// new node's id.
+    pub fn add_merge(&mut self, name: impl Into<String>, inputs: Vec<NodeId>) -> NodeId {
+        self.push_node(name.into(), GraphNodeKind::Merge, inputs)
+    }
+
+    fn push_node(&mut self, name: String, kind: GraphNodeKind, inputs: Vec<NodeId>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(GraphNode { name, kind, inputs });
+        id
+    }
 
-fn execute_stage(
-    ctx: &PipelineContext,
-    input: &PipelineData,
-    stage: &PipelineStage,
-) -> PipelineResult<PipelineData> {
-    match stage.kind {
-        StageKind::Retrieve => execute_retrieve(ctx, input, &stage.config, &stage.name),
-        StageKind::Infer => execute_infer(ctx, input, &stage.config, &stage.name),
-        StageKind::Reason => execute_reason(ctx, input, &stage.config, &stage.name),
-        StageKind::ExtractTriples => execute_extract_triples(input, &stage.config, &stage.name),
+    /// Mark `node` as the graph's output: the value returned as
+    /// [`PipelineOutput::result`] once execution completes.
+    pub fn set_output(&mut self, node: NodeId) {
+        self.output = Some(node);
     }
-}
 
-/// Extract seeds from any pipeline data variant.
-fn extract_seeds(data: &PipelineData) -> PipelineResult<Vec<SymbolId>> {
-    match data {
-        PipelineData::Seeds(seeds) => {
-            if seeds.is_empty() {
-                Err(PipelineError::NoSeeds)
-            } else {
-                Ok(seeds.clone())
-            }
-        }
-        PipelineData::Triples(triples) => {
-            let mut seeds: Vec<SymbolId> = triples
-                .iter()
-                .flat_map(|t| [t.subject, t.predicate, t.object])
-                .collect();
-            seeds.sort();
-            seeds.dedup();
-            if seeds.is_empty() {
-                Err(PipelineError::NoSeeds)
-            } else {
-                Ok(seeds)
-            }
-        }
-        PipelineData::Traversal(result) => {
-            let seeds: Vec<SymbolId> = result.visited.iter().copied().collect();
-            if seeds.is_empty() {
-                Err(PipelineError::NoSeeds)
-            } else {
-                Ok(seeds)
+    /// Topologically order the nodes via Kahn's algorithm, or report a cycle.
+    fn topological_order(&self) -> PipelineResult<Vec<NodeId>> {
+        let mut in_degree: Vec<usize> = self.nodes.iter().map(|n| n.inputs.len()).collect();
+        let mut dependents: Vec<Vec<NodeId>> = vec![Vec::new(); self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            for &input in &node.inputs {
+                dependents[input.0].push(NodeId(i));
             }
         }
-        PipelineData::Inference(result) => {
-            let seeds: Vec<SymbolId> = result.activations.iter().map(|(s, _)| *s).collect();
-            if seeds.is_empty() {
-                Err(PipelineError::NoSeeds)
-            } else {
-                Ok(seeds)
+
+        let mut ready: Vec<NodeId> = in_degree
+            .iter()
+            .enumerate()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(i, _)| NodeId(i))
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(node_id) = ready.pop() {
+            order.push(node_id);
+            for &dependent in &dependents[node_id.0] {
+                in_degree[dependent.0] -= 1;
+                if in_degree[dependent.0] == 0 {
+                    ready.push(dependent);
+                }
             }
         }
-        PipelineData::Reasoning(_) => Err(PipelineError::IncompatibleData {
-            stage_name: "extract_seeds".into(),
-            expected: "Seeds, Triples, Traversal, or Inference".into(),
-            actual: "Reasoning".into(),
-        }),
-    }
-}
-
-fn execute_retrieve(
-    ctx: &PipelineContext,
-    input: &PipelineData,
-    config: &StageConfig,
-    stage_name: &str,
-) -> PipelineResult<PipelineData> {
-    let seeds = extract_seeds(input)?;
-    let traversal_config = match config {
-        StageConfig::Retrieve { traversal } => traversal.clone(),
-        _ => TraversalConfig::default(),
-    };
 
-    let result = traverse_bfs(&ctx.knowledge_graph, &seeds, &traversal_config).map_err(|e| {
-        PipelineError::StageExecution {
-            stage_name: stage_name.into(),
-            message: format!("traversal failed: {e}"),
+        if order.len() != self.nodes.len() {
+            return Err(PipelineError::GraphCycle {
+                name: self.name.clone(),
+            });
         }
-    })?;
-
-    Ok(PipelineData::Traversal(result))
-}
 
-fn execute_infer(
-    ctx: &PipelineContext,
-    input: &PipelineData,
-    config: &StageConfig,
-    stage_name: &str,
-) -> PipelineResult<PipelineData> {
-    let seeds = extract_seeds(input)?;
+        Ok(order)
+    }
 
-    let query = match config {
-        StageConfig::Infer { query_template } => {
-            let mut q = query_template.clone();
-            q.seeds = seeds;
-            q
+    /// Run the graph with the given context and initial data, executing
+    /// nodes in topological order.
+    pub fn run(&self, ctx: &PipelineContext, initial: PipelineData) -> PipelineResult<PipelineOutput> {
+        if self.nodes.is_empty() {
+            return Err(PipelineError::EmptyPipeline {
+                name: self.name.clone(),
+            });
         }
-        _ => InferenceQuery::default().with_seeds(seeds),
-    };
 
-    let engine = InferEngine::new(
-        Arc::clone(&ctx.ops),
-        Arc::clone(&ctx.item_memory),
-        Arc::clone(&ctx.knowledge_graph),
-    );
+        let order = self.topological_order()?;
+        let mut results: std::collections::HashMap<NodeId, PipelineData> =
+            std::collections::HashMap::with_capacity(self.nodes.len());
+        let mut stage_results = Vec::with_capacity(self.nodes.len());
+
+        for node_id in order {
+            let node = &self.nodes[node_id.0];
+
+            let output = match &node.kind {
+                GraphNodeKind::Stage(stage) => {
+                    let input = match node.inputs.as_slice() {
+                        [] => &initial,
+                        [only] => &results[only],
+                        _ => {
+                            return Err(PipelineError::StageExecution {
+                                stage_name: node.name.clone(),
+                                message: format!(
+                                    "stage node {} has {} input edges, but a Stage node takes exactly one \
+                                     (use a Merge node to combine multiple inputs first)",
+                                    node.name,
+                                    node.inputs.len()
+                                ),
+                            });
+                        }
+                    };
+                    stage.execute(ctx, input)
+                }
+                GraphNodeKind::Merge => {
+                    if node.inputs.is_empty() {
+                        return Err(PipelineError::StageExecution {
+                            stage_name: node.name.clone(),
+                            message: "merge node has no input edges".into(),
+                        });
+                    }
+                    let inputs: Vec<&PipelineData> =
+                        node.inputs.iter().map(|id| &results[id]).collect();
+                    merge_pipeline_data(&inputs)
+                }
+            }
+            .map_err(|e| PipelineError::StageFailure {
+                pipeline_name: self.name.clone(),
+                stage_name: node.name.clone(),
+                stage_index: node_id.0,
+                source: Box::new(e),
+            })?;
 
-    let result = engine.infer_with_rules(&query, &ctx.rules).map_err(|e| {
-        PipelineError::StageExecution {
-            stage_name: stage_name.into(),
-            message: format!("inference failed: {e}"),
+            stage_results.push((node.name.clone(), output.clone()));
+            results.insert(node_id, output);
         }
-    })?;
 
-    Ok(PipelineData::Inference(result))
+        let output_id = self.output.ok_or_else(|| PipelineError::GraphNoOutput {
+            name: self.name.clone(),
+        })?;
+        let result = results.remove(&output_id).ok_or(PipelineError::GraphNoOutput {
+            name: self.name.clone(),
+        })?;
+
+        Ok(PipelineOutput {
+            result,
+            stages_executed: stage_results.len(),
+            stage_results,
+        })
+    }
 }
 
-fn execute_reason(
-    ctx: &PipelineContext,
-    input: &PipelineData,
-    config: &StageConfig,
-    stage_name: &str,
-) -> PipelineResult<PipelineData> {
-    let (max_iterations, node_limit) = match config {
-        StageConfig::Reason {
-            max_iterations,
-            node_limit,
-        } => (*max_iterations, *node_limit),
-        _ => (100, 10_000),
+/// Combine several upstream outputs into one [`PipelineData`], as performed
+/// by a [`GraphNodeKind::Merge`] node: seeds and triples are unioned
+/// (duplicates dropped), activations are concatenated. All inputs must share
+/// the same variant.
+fn merge_pipeline_data(inputs: &[&PipelineData]) -> PipelineResult<PipelineData> {
+    let Some((first, rest)) = inputs.split_first() else {
+        return Err(PipelineError::StageExecution {
+            stage_name: "merge".into(),
+            message: "no inputs to merge".into(),
+        });
     };
 
-    // Build an s-expression from inference activations or seeds.
-    let expr_str = match input {
-        PipelineData::Inference(result) => {
-            if result.activations.is_empty() {
-                return Ok(PipelineData::Reasoning(ReasoningResult {
-                    simplified_expr: String::new(),
-                    cost: 0,
-                    saturated: true,
-                }));
-            }
-            // Build a nested bundle of the top activations.
-            let ids: Vec<String> = result
-                .activations
-                .iter()
-                .take(10)
-                .map(|(s, _)| s.get().to_string())
-                .collect();
-            if ids.len() == 1 {
-                ids[0].clone()
-            } else {
-                ids.iter().skip(1).fold(ids[0].clone(), |acc, id| {
-                    format!("(bundle {} {})", acc, id)
-                })
-            }
-        }
-        PipelineData::Seeds(seeds) => {
-            if seeds.is_empty() {
-                return Err(PipelineError::NoSeeds);
-            }
-            let ids: Vec<String> = seeds.iter().map(|s| s.get().to_string()).collect();
-            if ids.len() == 1 {
-                ids[0].clone()
-            } else {
-                ids.iter().skip(1).fold(ids[0].clone(), |acc, id| {
-                    format!("(bundle {} {})", acc, id)
-                })
-            }
-        }
-        other => {
+    for other in rest {
+        if other.variant_name() != first.variant_name() {
             return Err(PipelineError::IncompatibleData {
-                stage_name: stage_name.into(),
-                expected: "Inference or Seeds".into(),
+                stage_name: "merge".into(),
+                expected: first.variant_name().into(),
                 actual: other.variant_name().into(),
             });
         }
-    };
-
-    let expr = expr_str
-        .parse::<egg::RecExpr<AkhLang>>()
-        .map_err(|e| PipelineError::StageExecution {
-            stage_name: stage_name.into(),
-            message: format!("expression parse failed: {e}"),
-        })?;
-
-    let runner = Runner::default()
-        .with_iter_limit(max_iterations)
-        .with_node_limit(node_limit)
-        .with_expr(&expr)
-        .run(&ctx.rules);
-
-    let saturated = runner.stop_reason.as_ref().is_some_and(|r| {
-        matches!(r, egg::StopReason::Saturated)
-    });
-
-    let extractor = Extractor::new(&runner.egraph, AstSize);
-    let (cost, best) = extractor.find_best(runner.roots[0]);
-
-    Ok(PipelineData::Reasoning(ReasoningResult {
-        simplified_expr: best.to_string(),
-        cost,
-        saturated,
-    }))
-}
-
-fn execute_extract_triples(
-    input: &PipelineData,
-    config: &StageConfig,
-    stage_name: &str,
-) -> PipelineResult<PipelineData> {
-    let min_confidence = match config {
-        StageConfig::ExtractTriples { min_confidence } => *min_confidence,
-        _ => 0.0,
-    };
+    }
 
-    match input {
-        PipelineData::Triples(triples) => {
-            let filtered: Vec<Triple> = triples
+    match first {
+        PipelineData::Seeds(_) => {
+            let mut seeds: Vec<SymbolId> = inputs
                 .iter()
-                .filter(|t| t.confidence >= min_confidence)
-                .cloned()
+                .flat_map(|d| match d {
+                    PipelineData::Seeds(s) => s.iter().copied(),
+                    _ => unreachable!("variant checked above"),
+                })
                 .collect();
-            Ok(PipelineData::Triples(filtered))
+            seeds.sort();
+            seeds.dedup();
+            Ok(PipelineData::Seeds(seeds))
         }
-        PipelineData::Traversal(result) => {
-            let filtered: Vec<Triple> = result
-                .triples
+        PipelineData::Triples(_) => {
+            let mut triples: Vec<Triple> = inputs
                 .iter()
-                .filter(|t| t.confidence >= min_confidence)
-                .cloned()
+                .flat_map(|d| match d {
+                    PipelineData::Triples(t) => t.iter().cloned(),
+                    _ => unreachable!("variant checked above"),
+                })
                 .collect();
-            Ok(PipelineData::Triples(filtered))
+            triples.sort_by_key(|t| (t.subject, t.predicate, t.object));
+            triples.dedup_by_key(|t| (t.subject, t.predicate, t.object));
+            Ok(PipelineData::Triples(triples))
         }
-        PipelineData::Inference(result) => {
-            // Convert activations to synthetic triples (activation → inferred_as → self)
-            // This is a simplified extraction; real use would have more domain logic.
-            let triples: Vec<Triple> = result
-                .activations
+        PipelineData::Inference(_) => {
+            let activations: Vec<(SymbolId, f32)> = inputs
                 .iter()
-                .filter(|(_, conf)| *conf >= min_confidence)
-                .map(|(sym, conf)| Triple::new(*sym, *sym, *sym).with_confidence(*conf))
+                .flat_map(|d| match d {
+                    PipelineData::Inference(r) => r.activations.iter().copied(),
+                    _ => unreachable!("variant checked above"),
+                })
                 .collect();
-            Ok(PipelineData::Triples(triples))
+            let provenance = inputs
+                .iter()
+                .flat_map(|d| match d {
+                    PipelineData::Inference(r) => r.provenance.iter().cloned(),
+                    _ => unreachable!("variant checked above"),
+                })
+                .collect();
+            Ok(PipelineData::Inference(InferenceResult {
+                activations,
+                pattern: None,
+                provenance,
+            }))
         }
         other => Err(PipelineError::IncompatibleData {
-            stage_name: stage_name.into(),
-            expected: "Triples, Traversal, or Inference".into(),
+            stage_name: "merge".into(),
+            expected: "Seeds, Triples, or Inference".into(),
             actual: other.variant_name().into(),
         }),
     }
@@ -545,16 +1427,13 @@ mod tests {
 
         let pipeline = Pipeline {
             name: "retrieve-only".into(),
-            stages: vec![PipelineStage {
-                name: "retrieve".into(),
-                kind: StageKind::Retrieve,
-                config: StageConfig::Retrieve {
-                    traversal: TraversalConfig {
-                        max_depth: 2,
-                        ..Default::default()
-                    },
+            stages: vec![Arc::new(RetrieveStage::new(
+                "retrieve",
+                TraversalConfig {
+                    max_depth: 2,
+                    ..Default::default()
                 },
-            }],
+            ))],
         };
 
         let output = pipeline
@@ -587,13 +1466,10 @@ mod tests {
 
         let pipeline = Pipeline {
             name: "infer-only".into(),
-            stages: vec![PipelineStage {
-                name: "infer".into(),
-                kind: StageKind::Infer,
-                config: StageConfig::Infer {
-                    query_template: InferenceQuery::default().with_max_depth(1),
-                },
-            }],
+            stages: vec![Arc::new(InferStage::new(
+                "infer",
+                InferenceQuery::default().with_max_depth(1),
+            ))],
         };
 
         let output = pipeline
@@ -632,6 +1508,46 @@ mod tests {
         assert!(matches!(output.result, PipelineData::Reasoning(_)));
     }
 
+    #[test]
+    fn reason_stage_reports_extraction_stats() {
+        let ctx = test_context();
+        let seeds = PipelineData::Seeds(vec![sym(1), sym(2), sym(3)]);
+
+        let stage = ReasonStage::new("reason", 10, 1000).with_top_k(3);
+        let output = stage.execute(&ctx, &seeds).unwrap();
+
+        match output {
+            PipelineData::Reasoning(result) => {
+                assert!(!result.simplified_expr.is_empty());
+                assert!(result.eclasses >= 1);
+                assert!(result.enodes >= 1);
+                assert!(result.alternatives.len() <= 2);
+                for alt in &result.alternatives {
+                    assert_ne!(alt.expr, result.simplified_expr);
+                }
+            }
+            other => panic!("expected Reasoning, got {}", other.variant_name()),
+        }
+    }
+
+    #[test]
+    fn reason_stage_cost_function_is_configurable() {
+        let ctx = test_context();
+        let seeds = PipelineData::Seeds(vec![sym(1), sym(2)]);
+
+        let by_size = ReasonStage::new("reason", 10, 1000)
+            .with_cost(CostFn::AstSize)
+            .execute(&ctx, &seeds)
+            .unwrap();
+        let by_depth = ReasonStage::new("reason", 10, 1000)
+            .with_cost(CostFn::AstDepth)
+            .execute(&ctx, &seeds)
+            .unwrap();
+
+        assert!(matches!(by_size, PipelineData::Reasoning(_)));
+        assert!(matches!(by_depth, PipelineData::Reasoning(_)));
+    }
+
     #[test]
     fn incompatible_data_error() {
         let ctx = test_context();
@@ -639,17 +1555,16 @@ mod tests {
             simplified_expr: "x".into(),
             cost: 1,
             saturated: true,
+            alternatives: vec![],
+            eclasses: 1,
+            enodes: 1,
+            iterations: 0,
+            rules_applied: vec![],
         });
 
         let pipeline = Pipeline {
             name: "bad".into(),
-            stages: vec![PipelineStage {
-                name: "extract".into(),
-                kind: StageKind::ExtractTriples,
-                config: StageConfig::ExtractTriples {
-                    min_confidence: 0.0,
-                },
-            }],
+            stages: vec![Arc::new(ExtractTriplesStage::new("extract", 0.0))],
         };
 
         let result = pipeline.run(&ctx, reasoning);
@@ -683,7 +1598,257 @@ mod tests {
             simplified_expr: "x".into(),
             cost: 1,
             saturated: true,
+            alternatives: vec![],
+            eclasses: 1,
+            enodes: 1,
+            iterations: 0,
+            rules_applied: vec![],
         });
         assert!(extract_seeds(&reasoning_data).is_err());
     }
+
+    #[test]
+    fn custom_stage_plugs_into_pipeline() {
+        #[derive(Debug)]
+        struct UppercaseLabelStage;
+
+        impl Stage for UppercaseLabelStage {
+            fn name(&self) -> &str {
+                "uppercase_label"
+            }
+
+            fn execute(
+                &self,
+                _ctx: &PipelineContext,
+                input: &PipelineData,
+            ) -> PipelineResult<PipelineData> {
+                // A trivial custom stage: pass Seeds through unchanged, proving
+                // a third-party Stage impl can be boxed straight into `stages`.
+                match input {
+                    PipelineData::Seeds(seeds) => Ok(PipelineData::Seeds(seeds.clone())),
+                    other => Err(PipelineError::IncompatibleData {
+                        stage_name: "uppercase_label".into(),
+                        expected: "Seeds".into(),
+                        actual: other.variant_name().into(),
+                    }),
+                }
+            }
+        }
+
+        let ctx = test_context();
+        let pipeline = Pipeline {
+            name: "custom".into(),
+            stages: vec![Arc::new(UppercaseLabelStage)],
+        };
+
+        let output = pipeline.run(&ctx, PipelineData::Seeds(vec![sym(1)])).unwrap();
+        assert_eq!(output.stage_results[0].0, "uppercase_label");
+        assert!(matches!(output.result, PipelineData::Seeds(_)));
+    }
+
+    #[test]
+    fn run_interruptible_completes_when_not_interrupted() {
+        let ctx = test_context();
+        let a = sym(1);
+        let r = sym(10);
+        let b = sym(2);
+        ctx.knowledge_graph
+            .insert_triple(&Triple::new(a, r, b))
+            .unwrap();
+
+        let pipeline = Pipeline {
+            name: "retrieve-only".into(),
+            stages: vec![Arc::new(RetrieveStage::new(
+                "retrieve",
+                TraversalConfig::default(),
+            ))],
+        };
+
+        let interrupt = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let output = pipeline
+            .run_interruptible(&ctx, PipelineData::Seeds(vec![a]), interrupt)
+            .unwrap();
+        assert_eq!(output.stages_executed, 1);
+    }
+
+    #[test]
+    fn run_interruptible_stops_between_stages() {
+        let ctx = test_context();
+        let a = sym(1);
+        let r = sym(10);
+        let b = sym(2);
+        ctx.knowledge_graph
+            .insert_triple(&Triple::new(a, r, b))
+            .unwrap();
+
+        let pipeline = Pipeline {
+            name: "retrieve-then-extract".into(),
+            stages: vec![
+                Arc::new(RetrieveStage::new("retrieve", TraversalConfig::default())),
+                Arc::new(ExtractTriplesStage::new("extract", 0.0)),
+            ],
+        };
+
+        // Already interrupted before the pipeline starts: no stage should run.
+        let interrupt = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let err = pipeline
+            .run_interruptible(&ctx, PipelineData::Seeds(vec![a]), interrupt)
+            .unwrap_err();
+
+        assert!(matches!(err.error, PipelineError::Interrupted { stage_index: 0, .. }));
+        assert_eq!(err.partial.stages_executed, 0);
+    }
+
+    #[test]
+    fn validate_accepts_builtin_pipelines() {
+        assert!(Pipeline::query_pipeline().validate(DataShape::Seeds).is_ok());
+        // The ingest pipeline's single ExtractTriplesStage filters an
+        // already-extracted set of triples; it doesn't accept Seeds.
+        assert!(Pipeline::ingest_pipeline().validate(DataShape::Triples).is_ok());
+    }
+
+    #[test]
+    fn validate_collects_every_incompatibility() {
+        // Reason only accepts Inference/Seeds, so placing it right after
+        // Retrieve (which produces Traversal) is incompatible; and feeding
+        // ExtractTriples straight after that Reasoning output is too.
+        let pipeline = Pipeline {
+            name: "bad".into(),
+            stages: vec![
+                Arc::new(RetrieveStage::new("retrieve", TraversalConfig::default())),
+                Arc::new(ReasonStage::new("reason", 10, 1000)),
+                Arc::new(ExtractTriplesStage::new("extract", 0.0)),
+            ],
+        };
+
+        let errors = pipeline.validate(DataShape::Seeds).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0],
+            PipelineValidationError::IncompatibleStage { stage_index: 1, .. }
+        ));
+        assert!(matches!(
+            errors[1],
+            PipelineValidationError::IncompatibleStage { stage_index: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn validate_reports_missing_seed_source() {
+        // ExtractTriples doesn't accept Seeds, so it can't be the first stage.
+        let pipeline = Pipeline {
+            name: "no-seed-source".into(),
+            stages: vec![Arc::new(ExtractTriplesStage::new("extract", 0.0))],
+        };
+
+        let errors = pipeline.validate(DataShape::Seeds).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            PipelineValidationError::MissingSeedSource { stage_index: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn validate_reports_empty_pipeline() {
+        let pipeline = Pipeline {
+            name: "empty".into(),
+            stages: vec![],
+        };
+        let errors = pipeline.validate(DataShape::Seeds).unwrap_err();
+        assert!(matches!(errors[0], PipelineValidationError::EmptyPipeline { .. }));
+    }
+
+    #[test]
+    fn graph_fan_out_and_merge_inference() {
+        let ctx = test_context();
+        let sun = sym(1);
+        let is_a = sym(2);
+        let star = sym(3);
+        ctx.item_memory.get_or_create(&ctx.ops, sun);
+        ctx.item_memory.get_or_create(&ctx.ops, is_a);
+        ctx.item_memory.get_or_create(&ctx.ops, star);
+        ctx.knowledge_graph
+            .insert_triple(&Triple::new(sun, is_a, star))
+            .unwrap();
+
+        let mut graph = PipelineGraph::new("fan-out");
+        let infer_a = graph.add_stage(
+            "infer_a",
+            Arc::new(InferStage::new(
+                "infer_a",
+                InferenceQuery::default().with_max_depth(1),
+            )),
+            vec![],
+        );
+        let infer_b = graph.add_stage(
+            "infer_b",
+            Arc::new(InferStage::new(
+                "infer_b",
+                InferenceQuery::default().with_max_depth(1),
+            )),
+            vec![],
+        );
+        let merged = graph.add_merge("merge", vec![infer_a, infer_b]);
+        graph.set_output(merged);
+
+        let output = graph.run(&ctx, PipelineData::Seeds(vec![sun])).unwrap();
+        assert_eq!(output.stages_executed, 3);
+        match output.result {
+            PipelineData::Inference(result) => {
+                // Both branches independently activate Star; merge concatenates
+                // rather than deduplicating.
+                let star_hits = result.activations.iter().filter(|(s, _)| *s == star).count();
+                assert_eq!(star_hits, 2);
+            }
+            other => panic!("expected Inference, got {}", other.variant_name()),
+        }
+    }
+
+    #[test]
+    fn graph_merge_rejects_mismatched_variants() {
+        let ctx = test_context();
+        let mut graph = PipelineGraph::new("bad-merge");
+        let seeds_node = graph.add_stage(
+            "retrieve",
+            Arc::new(RetrieveStage::new("retrieve", TraversalConfig::default())),
+            vec![],
+        );
+        let extract_node = graph.add_stage(
+            "extract",
+            Arc::new(ExtractTriplesStage::new("extract", 0.0)),
+            vec![seeds_node],
+        );
+        let merged = graph.add_merge("merge", vec![seeds_node, extract_node]);
+        graph.set_output(merged);
+
+        let a = sym(1);
+        ctx.knowledge_graph
+            .insert_triple(&Triple::new(a, sym(10), sym(2)))
+            .unwrap();
+        let result = graph.run(&ctx, PipelineData::Seeds(vec![a]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn pipeline_still_runs_after_lowering_to_graph() {
+        let ctx = test_context();
+        let a = sym(1);
+        let r = sym(10);
+        let b = sym(2);
+        ctx.knowledge_graph
+            .insert_triple(&Triple::new(a, r, b))
+            .unwrap();
+
+        let pipeline = Pipeline {
+            name: "retrieve-only".into(),
+            stages: vec![Arc::new(RetrieveStage::new(
+                "retrieve",
+                TraversalConfig::default(),
+            ))],
+        };
+        let output = pipeline.run(&ctx, PipelineData::Seeds(vec![a])).unwrap();
+        assert_eq!(output.stage_results[0].0, "retrieve");
+        assert!(matches!(output.result, PipelineData::Traversal(_)));
+    }
 }
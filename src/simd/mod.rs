@@ -8,15 +8,21 @@
 //!
 //! - **Generic**: Pure-Rust scalar fallback — works everywhere (illumos, ARM, etc.)
 //! - **AVX2**: 256-bit SIMD for x86_64 Linux/illumos systems with AVX2 support
+//! - **Portable** (`portable-simd` feature): `std::simd`-backed fallback for
+//!   targets with no hand-written kernel (aarch64, wasm, ...)
 
 pub mod avx2;
 pub mod generic;
+#[cfg(feature = "portable-simd")]
+pub mod portable;
 
 /// Instruction set architecture level detected at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum IsaLevel {
     /// Pure-Rust scalar operations, no SIMD.
     Generic,
+    /// `std::simd` portable-SIMD kernel (requires the `portable-simd` feature).
+    Portable,
     /// x86_64 AVX2 (256-bit vectors).
     Avx2,
 }
@@ -25,6 +31,7 @@ impl std::fmt::Display for IsaLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             IsaLevel::Generic => write!(f, "Generic (scalar)"),
+            IsaLevel::Portable => write!(f, "Portable (std::simd)"),
             IsaLevel::Avx2 => write!(f, "AVX2 (256-bit)"),
         }
     }
@@ -38,6 +45,11 @@ pub fn detect_isa() -> IsaLevel {
             return IsaLevel::Avx2;
         }
     }
+    #[cfg(feature = "portable-simd")]
+    {
+        return IsaLevel::Portable;
+    }
+    #[allow(unreachable_code)]
     IsaLevel::Generic
 }
 
@@ -77,6 +89,8 @@ pub fn best_kernel() -> Box<dyn VsaKernel> {
     match detect_isa() {
         #[cfg(target_arch = "x86_64")]
         IsaLevel::Avx2 => Box::new(avx2::Avx2Kernel),
+        #[cfg(feature = "portable-simd")]
+        IsaLevel::Portable => Box::new(portable::PortableSimdKernel),
         _ => Box::new(generic::GenericKernel),
     }
 }
@@ -180,4 +194,10 @@ mod tests {
             kernel_conformance_tests(&avx2::Avx2Kernel);
         }
     }
+
+    #[cfg(feature = "portable-simd")]
+    #[test]
+    fn portable_simd_kernel_conformance() {
+        kernel_conformance_tests(&portable::PortableSimdKernel);
+    }
 }
@@ -0,0 +1,163 @@
+//! Portable-SIMD kernel for VSA operations, backed by `std::simd`.
+//!
+//! Unlike [`super::avx2::Avx2Kernel`], this kernel is not tied to a specific
+//! target architecture: it vectorizes via the portable-simd API, so it gets
+//! consistent acceleration on aarch64, wasm, and any other target the
+//! compiler can lower `std::simd` lanes for, without hand-written per-ISA
+//! code. Requires the nightly `portable_simd` feature, so it's gated behind
+//! the `portable-simd` crate feature.
+
+use std::simd::num::SimdInt;
+use std::simd::{u8x32, Simd};
+
+use super::{IsaLevel, VsaKernel};
+
+/// Number of lanes used for the `u8`-based chunked operations.
+const LANES: usize = 32;
+
+/// Portable-SIMD VSA kernel using `std::simd` lane-wise operations.
+#[derive(Debug, Clone, Copy)]
+pub struct PortableSimdKernel;
+
+impl VsaKernel for PortableSimdKernel {
+    fn isa_level(&self) -> IsaLevel {
+        IsaLevel::Portable
+    }
+
+    fn xor_bind(&self, a: &[u8], b: &[u8], out: &mut [u8]) {
+        debug_assert_eq!(a.len(), b.len());
+        debug_assert_eq!(a.len(), out.len());
+
+        let chunks = a.len() / LANES;
+        for i in 0..chunks {
+            let start = i * LANES;
+            let va = u8x32::from_slice(&a[start..start + LANES]);
+            let vb = u8x32::from_slice(&b[start..start + LANES]);
+            (va ^ vb).copy_to_slice(&mut out[start..start + LANES]);
+        }
+        for i in (chunks * LANES)..a.len() {
+            out[i] = a[i] ^ b[i];
+        }
+    }
+
+    fn bundle_add_i8(&self, acc: &mut [i8], src: &[i8]) {
+        debug_assert_eq!(acc.len(), src.len());
+
+        let chunks = acc.len() / LANES;
+        for i in 0..chunks {
+            let start = i * LANES;
+            let vacc = Simd::<i8, LANES>::from_slice(&acc[start..start + LANES]);
+            let vsrc = Simd::<i8, LANES>::from_slice(&src[start..start + LANES]);
+            vacc.saturating_add(vsrc)
+                .copy_to_slice(&mut acc[start..start + LANES]);
+        }
+        for i in (chunks * LANES)..acc.len() {
+            acc[i] = acc[i].saturating_add(src[i]);
+        }
+    }
+
+    fn hamming_distance(&self, a: &[u8], b: &[u8]) -> u32 {
+        debug_assert_eq!(a.len(), b.len());
+
+        let mut total = 0u32;
+        let chunks = a.len() / LANES;
+        for i in 0..chunks {
+            let start = i * LANES;
+            let va = u8x32::from_slice(&a[start..start + LANES]);
+            let vb = u8x32::from_slice(&b[start..start + LANES]);
+            let xored = va ^ vb;
+            // `std::simd` has no portable popcount yet, so the chunked XOR
+            // is vectorized and the bit-counting is a scalar fold over it.
+            for byte in xored.to_array() {
+                total += byte.count_ones();
+            }
+        }
+        for i in (chunks * LANES)..a.len() {
+            total += (a[i] ^ b[i]).count_ones();
+        }
+        total
+    }
+
+    fn cosine_similarity_i8(&self, a: &[i8], b: &[i8]) -> f32 {
+        debug_assert_eq!(a.len(), b.len());
+
+        let mut dot: i64 = 0;
+        let mut norm_a: i64 = 0;
+        let mut norm_b: i64 = 0;
+
+        let chunks = a.len() / LANES;
+        for i in 0..chunks {
+            let start = i * LANES;
+            let va = Simd::<i16, LANES>::from_array(
+                std::array::from_fn(|j| a[start + j] as i16),
+            );
+            let vb = Simd::<i16, LANES>::from_array(
+                std::array::from_fn(|j| b[start + j] as i16),
+            );
+            let prod = va * vb;
+            let sq_a = va * va;
+            let sq_b = vb * vb;
+            dot += prod.cast::<i64>().reduce_sum();
+            norm_a += sq_a.cast::<i64>().reduce_sum();
+            norm_b += sq_b.cast::<i64>().reduce_sum();
+        }
+        for i in (chunks * LANES)..a.len() {
+            let av = a[i] as i64;
+            let bv = b[i] as i64;
+            dot += av * bv;
+            norm_a += av * av;
+            norm_b += bv * bv;
+        }
+
+        let denom = ((norm_a as f64).sqrt() * (norm_b as f64).sqrt()) as f32;
+        if denom == 0.0 {
+            return 0.0;
+        }
+        (dot as f32) / denom
+    }
+
+    fn permute(&self, data: &[u8], shift: usize, out: &mut [u8]) {
+        debug_assert_eq!(data.len(), out.len());
+        let total_bits = data.len() * 8;
+        if total_bits == 0 {
+            return;
+        }
+        let shift = shift % total_bits;
+        if shift == 0 {
+            out.copy_from_slice(data);
+            return;
+        }
+
+        // A lane-wise bit-rotate across byte boundaries needs cross-lane
+        // shuffles that `std::simd` doesn't expose portably for arbitrary
+        // bit shifts, so the SIMD pass only copies the bytes; the whole-byte
+        // and sub-byte rotations are scalar. Bits are numbered MSB-first and
+        // move to higher positions, as in `GenericKernel::permute`.
+        let byte_shift = shift / 8;
+        let bit_shift = shift % 8;
+
+        let mut byte_rotated = vec![0u8; data.len()];
+        let chunks = data.len() / LANES;
+        for i in 0..chunks {
+            let start = i * LANES;
+            let v = u8x32::from_slice(&data[start..start + LANES]);
+            v.copy_to_slice(&mut byte_rotated[start..start + LANES]);
+        }
+        if chunks * LANES < data.len() {
+            byte_rotated[chunks * LANES..].copy_from_slice(&data[chunks * LANES..]);
+        }
+        byte_rotated.rotate_right(byte_shift);
+
+        if bit_shift == 0 {
+            out.copy_from_slice(&byte_rotated);
+            return;
+        }
+
+        let len = byte_rotated.len();
+        for i in 0..len {
+            let cur = byte_rotated[i];
+            let prev = byte_rotated[(i + len - 1) % len];
+            out[i] = (cur >> bit_shift) | (prev << (8 - bit_shift));
+        }
+    }
+}
@@ -6,6 +6,8 @@
 //! - Fast ANN search: find the most similar symbols to a query vector
 //! - Concurrent access via DashMap for the symbol registry
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::sync::RwLock;
 
 use anndists::dist::DistHamming;
@@ -45,6 +47,9 @@ pub struct ItemMemory {
     /// Dimension and encoding config.
     dim: Dimension,
     encoding: Encoding,
+    /// Lazy mode: regenerate base vectors from [`HyperVec::from_seed`] on
+    /// every [`ItemMemory::get_or_create`] miss instead of storing them.
+    lazy: bool,
 }
 
 // Safety: Hnsw uses internal synchronization via atomics/locks.
@@ -73,18 +78,38 @@ impl ItemMemory {
             next_hnsw_id: std::sync::atomic::AtomicUsize::new(0),
             dim,
             encoding,
+            lazy: false,
+        }
+    }
+
+    /// Create a new item memory that never stores base vectors.
+    ///
+    /// [`ItemMemory::get_or_create`] regenerates a symbol's vector from
+    /// [`HyperVec::from_seed`] on every miss instead of caching it, trading
+    /// a bit of recomputation for O(1) memory regardless of codebook size.
+    /// Vectors explicitly passed to [`ItemMemory::insert`] (or produced by
+    /// [`ItemMemory::insert_batch`]) are still stored and searchable as usual.
+    pub fn new_lazy(dim: Dimension, encoding: Encoding, max_elements: usize) -> Self {
+        Self {
+            lazy: true,
+            ..Self::new(dim, encoding, max_elements)
         }
     }
 
     /// Get the hypervector for a symbol, creating it if it doesn't exist.
     ///
-    /// The vector is deterministically derived from the symbol ID,
-    /// so this is idempotent.
+    /// The vector is deterministically derived from the symbol ID, so this
+    /// is idempotent. In lazy mode (see [`ItemMemory::new_lazy`]), a miss
+    /// regenerates the vector on the spot rather than storing it.
     pub fn get_or_create(&self, ops: &VsaOps, symbol: SymbolId) -> HyperVec {
         if let Some(entry) = self.vectors.get(&symbol) {
             return entry.value().clone();
         }
 
+        if self.lazy {
+            return HyperVec::from_seed(symbol, self.dim, self.encoding);
+        }
+
         let vec = encode_symbol(ops, symbol);
         self.insert(symbol, vec.clone());
         vec
@@ -117,6 +142,21 @@ impl ItemMemory {
         self.vectors.contains_key(&symbol)
     }
 
+    /// The dimension this item memory stores vectors at.
+    pub fn dim(&self) -> Dimension {
+        self.dim
+    }
+
+    /// The encoding this item memory stores vectors with.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Iterate over every stored `(SymbolId, HyperVec)` pair, in unspecified order.
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, HyperVec)> + '_ {
+        self.vectors.iter().map(|entry| (*entry.key(), entry.value().clone()))
+    }
+
     /// Number of symbols stored.
     pub fn len(&self) -> usize {
         self.vectors.len()
@@ -166,6 +206,55 @@ impl ItemMemory {
         Ok(results)
     }
 
+    /// Clean up a noisy query vector to the `k` nearest stored atoms.
+    ///
+    /// Unlike [`ItemMemory::search`]'s approximate HNSW lookup, this computes
+    /// exact similarity against every entry in the codebook via a bounded
+    /// min-heap of size `k` — memory stays O(k) regardless of codebook size,
+    /// at O(n log k) time. This is the operation VSA unbinding depends on:
+    /// the noisy vector produced by `unbind` must be snapped back to the
+    /// nearest known atom before it means anything.
+    ///
+    /// Returns results sorted by descending similarity; ties are broken by
+    /// ascending `SymbolId` so the result is deterministic. An empty codebook
+    /// returns an empty vec, and `k` larger than the codebook returns
+    /// everything.
+    pub fn cleanup(&self, ops: &VsaOps, query: &HyperVec, k: usize) -> VsaResult<Vec<(SymbolId, f32)>> {
+        if query.dim() != self.dim {
+            return Err(VsaError::DimensionMismatch {
+                expected: self.dim.0,
+                actual: query.dim().0,
+            });
+        }
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut heap: BinaryHeap<Reverse<Candidate>> = BinaryHeap::with_capacity(k + 1);
+        for entry in self.vectors.iter() {
+            let similarity = ops.similarity(query, entry.value())?;
+            heap.push(Reverse(Candidate {
+                similarity,
+                symbol: *entry.key(),
+            }));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+
+        let mut results: Vec<(SymbolId, f32)> = heap
+            .into_iter()
+            .map(|Reverse(c)| (c.symbol, c.similarity))
+            .collect();
+        results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then_with(|| a.0.cmp(&b.0)));
+        Ok(results)
+    }
+
+    /// The `k = 1` convenience wrapper around [`ItemMemory::cleanup`].
+    pub fn cleanup_best(&self, ops: &VsaOps, query: &HyperVec) -> VsaResult<Option<(SymbolId, f32)>> {
+        Ok(self.cleanup(ops, query, 1)?.into_iter().next())
+    }
+
     /// Batch insert using rayon for parallel encoding.
     pub fn insert_batch(&self, ops: &VsaOps, symbols: &[SymbolId]) {
         use rayon::prelude::*;
@@ -190,10 +279,39 @@ impl std::fmt::Debug for ItemMemory {
             .field("dim", &self.dim)
             .field("encoding", &self.encoding)
             .field("len", &self.vectors.len())
+            .field("lazy", &self.lazy)
             .finish()
     }
 }
 
+/// An entry in [`ItemMemory::cleanup`]'s bounded min-heap.
+///
+/// Ordered by similarity first; on a tie, a *larger* symbol sorts as
+/// smaller, so it is the one evicted when the heap exceeds `k` — leaving
+/// the smallest `SymbolId` as the deterministic tie-break winner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    similarity: f32,
+    symbol: SymbolId,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.similarity
+            .partial_cmp(&other.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| other.symbol.cmp(&self.symbol))
+    }
+}
+
 /// Convert a byte slice to a `Vec<u32>` for HNSW Hamming distance.
 fn bytes_to_u32_vec(bytes: &[u8]) -> Vec<u32> {
     let mut result = Vec::with_capacity((bytes.len() + 3) / 4);
@@ -209,6 +327,7 @@ fn bytes_to_u32_vec(bytes: &[u8]) -> Vec<u32> {
 mod tests {
     use super::*;
     use crate::simd;
+    use rand::SeedableRng;
 
     fn test_ops() -> VsaOps {
         VsaOps::new(simd::best_kernel(), Dimension::TEST, Encoding::Bipolar)
@@ -247,6 +366,98 @@ mod tests {
         assert!((results[0].similarity - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn cleanup_finds_self_exactly() {
+        let ops = test_ops();
+        let mem = ItemMemory::new(Dimension::TEST, Encoding::Bipolar, 100);
+
+        for i in 1..=10u64 {
+            let sym = SymbolId::new(i).unwrap();
+            mem.get_or_create(&ops, sym);
+        }
+
+        let query_sym = SymbolId::new(5).unwrap();
+        let query_vec = mem.get(query_sym).unwrap();
+        let results = mem.cleanup(&ops, &query_vec, 3).unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, query_sym);
+        assert!((results[0].1 - 1.0).abs() < 0.001);
+        // Sorted by descending similarity.
+        assert!(results[0].1 >= results[1].1 && results[1].1 >= results[2].1);
+    }
+
+    #[test]
+    fn cleanup_k_larger_than_codebook_returns_all() {
+        let ops = test_ops();
+        let mem = ItemMemory::new(Dimension::TEST, Encoding::Bipolar, 100);
+        for i in 1..=3u64 {
+            mem.get_or_create(&ops, SymbolId::new(i).unwrap());
+        }
+
+        let query = mem.get(SymbolId::new(1).unwrap()).unwrap();
+        let results = mem.cleanup(&ops, &query, 100).unwrap();
+        assert_eq!(results.len(), 3);
+    }
+
+    #[test]
+    fn cleanup_empty_codebook_returns_empty() {
+        let ops = test_ops();
+        let mem = ItemMemory::new(Dimension::TEST, Encoding::Bipolar, 100);
+        let query = HyperVec::zero(Dimension::TEST, Encoding::Bipolar);
+        assert!(mem.cleanup(&ops, &query, 5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn cleanup_best_matches_cleanup_top_one() {
+        let ops = test_ops();
+        let mem = ItemMemory::new(Dimension::TEST, Encoding::Bipolar, 100);
+        for i in 1..=5u64 {
+            mem.get_or_create(&ops, SymbolId::new(i).unwrap());
+        }
+
+        let query = mem.get(SymbolId::new(2).unwrap()).unwrap();
+        let best = mem.cleanup_best(&ops, &query).unwrap().unwrap();
+        let top = mem.cleanup(&ops, &query, 1).unwrap();
+        assert_eq!(best, top[0]);
+    }
+
+    #[test]
+    fn lazy_get_or_create_does_not_store() {
+        let ops = test_ops();
+        let mem = ItemMemory::new_lazy(Dimension::TEST, Encoding::Bipolar, 1000);
+        let sym = SymbolId::new(1).unwrap();
+
+        let v1 = mem.get_or_create(&ops, sym);
+        assert_eq!(mem.len(), 0, "lazy mode must not store the regenerated vector");
+        assert!(!mem.contains(sym));
+
+        let v2 = mem.get_or_create(&ops, sym);
+        assert_eq!(v1, v2, "regeneration from the seed must be deterministic");
+    }
+
+    #[test]
+    fn lazy_get_or_create_matches_from_seed() {
+        let ops = test_ops();
+        let mem = ItemMemory::new_lazy(Dimension::TEST, Encoding::Bipolar, 1000);
+        let sym = SymbolId::new(42).unwrap();
+
+        let generated = mem.get_or_create(&ops, sym);
+        let expected = HyperVec::from_seed(sym, Dimension::TEST, Encoding::Bipolar);
+        assert_eq!(generated, expected);
+    }
+
+    #[test]
+    fn lazy_insert_is_still_stored_and_searchable() {
+        let ops = test_ops();
+        let mem = ItemMemory::new_lazy(Dimension::TEST, Encoding::Bipolar, 1000);
+        let sym = SymbolId::new(5).unwrap();
+
+        mem.insert(sym, ops.random(&mut rand::rngs::StdRng::from_seed([7u8; 32])));
+        assert!(mem.contains(sym));
+        assert_eq!(mem.len(), 1);
+    }
+
     #[test]
     fn batch_insert() {
         let ops = test_ops();
@@ -7,15 +7,74 @@
 //! - [`VsaOps`] — bind, bundle, permute, similarity operations
 //! - [`ItemMemory`] — symbol-to-vector mapping with ANN search
 //! - Encoding from symbols to vectors
+//! - [`resonator`] — recover the factors of a bound composite hypervector
 
 pub mod code_encode;
 pub mod encode;
 pub mod grounding;
 pub mod item_memory;
 pub mod ops;
+pub mod persist;
+pub mod resonator;
 
 use serde::{Deserialize, Serialize};
 
+use crate::symbol::SymbolId;
+
+/// Number of 32-bit words kept in the lagged-Fibonacci generator's ring
+/// buffer (the `k` lag).
+const LFG_K: usize = 55;
+
+/// The shorter lag (`j`) used alongside `LFG_K` in the recurrence
+/// `S_n = (S_{n-j} + S_{n-k}) mod 2^32`.
+const LFG_J: usize = 24;
+
+/// Mix a 64-bit state with splitmix64, advancing it and returning the mixed output.
+///
+/// Used only to expand a [`SymbolId`] into the lagged-Fibonacci generator's
+/// initial seed words — not a general-purpose RNG.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Lagged-Fibonacci generator (LFG) for deterministic, platform-independent
+/// hypervector bit streams.
+///
+/// `S_n = (S_{n-j} + S_{n-k}) mod 2^32`, with the initial `k` words seeded
+/// from a [`SymbolId`] via splitmix64. Unlike a general PRNG, this exists
+/// purely so the same symbol always regenerates the same bit stream, so a
+/// base vector never needs to be persisted — see [`HyperVec::from_seed`].
+struct LaggedFibonacci {
+    buf: [u32; LFG_K],
+    pos: usize,
+}
+
+impl LaggedFibonacci {
+    fn seeded(seed: u64) -> Self {
+        let mut state = seed;
+        let mut buf = [0u32; LFG_K];
+        for slot in buf.iter_mut() {
+            *slot = (splitmix64(&mut state) & 0xFFFF_FFFF) as u32;
+        }
+        Self { buf, pos: 0 }
+    }
+
+    /// Produce the next 32-bit word in the sequence.
+    fn next_word(&mut self) -> u32 {
+        // `self.pos` holds S_{n-k} (about to be overwritten); the word
+        // `LFG_J` slots back in time holds S_{n-j}.
+        let tap_j = (self.pos + LFG_K - LFG_J) % LFG_K;
+        let value = self.buf[tap_j].wrapping_add(self.buf[self.pos]);
+        self.buf[self.pos] = value;
+        self.pos = (self.pos + 1) % LFG_K;
+        value
+    }
+}
+
 /// Configurable hypervector dimensionality.
 ///
 /// Typical values: 10,000 for good capacity, 1,000 for testing.
@@ -115,6 +174,42 @@ impl HyperVec {
         }
     }
 
+    /// Deterministically regenerate the base hypervector for `id`, without
+    /// needing to have ever stored it.
+    ///
+    /// Streams bits from a [`LaggedFibonacci`] generator seeded from `id`
+    /// (via splitmix64) into successive components, masking the trailing
+    /// byte to `dim`'s exact bit count. The same `id`, `dim`, and `encoding`
+    /// always reproduce the same bit-identical vector, independent of
+    /// platform or Rust version — mirroring how disc-image tools regenerate
+    /// filler regions from a PRNG seed instead of storing them. See
+    /// [`item_memory::ItemMemory`] for the lazy mode this enables.
+    pub fn from_seed(id: SymbolId, dim: Dimension, encoding: Encoding) -> Self {
+        let byte_len = match encoding {
+            Encoding::Bipolar => dim.binary_byte_len(),
+        };
+        let mut data = vec![0u8; byte_len];
+        let mut lfg = LaggedFibonacci::seeded(id.get());
+
+        let mut bit_index = 0usize;
+        'fill: loop {
+            let word = lfg.next_word();
+            for bit in 0..32 {
+                if bit_index >= dim.0 {
+                    break 'fill;
+                }
+                if (word >> bit) & 1 == 1 {
+                    let byte_idx = bit_index / 8;
+                    let bit_idx = bit_index % 8;
+                    data[byte_idx] |= 1 << bit_idx;
+                }
+                bit_index += 1;
+            }
+        }
+
+        Self { data, dim, encoding }
+    }
+
     /// Raw byte data of this hypervector.
     pub fn data(&self) -> &[u8] {
         &self.data
@@ -194,4 +289,35 @@ mod tests {
         hv.set_bit(0, false);
         assert!(!hv.get_bit(0));
     }
+
+    #[test]
+    fn from_seed_is_deterministic() {
+        let id = SymbolId::new(1234).unwrap();
+        let a = HyperVec::from_seed(id, Dimension::TEST, Encoding::Bipolar);
+        let b = HyperVec::from_seed(id, Dimension::TEST, Encoding::Bipolar);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn from_seed_masks_trailing_bits() {
+        let id = SymbolId::new(7).unwrap();
+        let hv = HyperVec::from_seed(id, Dimension(10), Encoding::Bipolar);
+        assert_eq!(hv.byte_len(), 2);
+        // Only the low 2 bits of the trailing byte belong to the 10-bit vector.
+        assert_eq!(hv.data()[1] & 0b1111_1100, 0);
+    }
+
+    #[test]
+    fn from_seed_is_near_orthogonal_across_ids() {
+        let a = HyperVec::from_seed(SymbolId::new(1).unwrap(), Dimension::TEST, Encoding::Bipolar);
+        let b = HyperVec::from_seed(SymbolId::new(2).unwrap(), Dimension::TEST, Encoding::Bipolar);
+        let matching: usize = (0..Dimension::TEST.0)
+            .filter(|&i| a.get_bit(i) == b.get_bit(i))
+            .count();
+        let fraction = matching as f64 / Dimension::TEST.0 as f64;
+        assert!(
+            (0.4..0.6).contains(&fraction),
+            "expected near-orthogonal vectors, got {fraction} matching bits"
+        );
+    }
 }
@@ -3,11 +3,20 @@
 //! These are the core algebraic operations of the Vector Symbolic Architecture.
 //! Each operation is dispatched through the SIMD kernel for maximum performance.
 
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+
 use crate::error::VsaError;
 use crate::simd::VsaKernel;
 
 use super::{Dimension, Encoding, HyperVec};
 
+/// Domain-separation prefix mixed into every [`VsaOps::atom_from_label`] hash,
+/// so this crate's label codebook can't collide with another hashing use of
+/// the same label elsewhere.
+const ATOM_LABEL_DOMAIN: &[u8] = b"akh-medu/vsa/atom-from-label";
+
 /// Result type for VSA operations.
 pub type VsaResult<T> = std::result::Result<T, VsaError>;
 
@@ -74,6 +83,34 @@ impl VsaOps {
         HyperVec::from_raw(data, self.dim, self.encoding)
     }
 
+    /// Derive a stable atomic hypervector for `label`, deterministic across
+    /// processes and machines.
+    ///
+    /// Unlike [`VsaOps::random`], the same label always produces the same
+    /// vector, so a concept→vector codebook doesn't need to be persisted —
+    /// only the label does. Hashes `label` into a 32-byte seed via SHA-256
+    /// (domain-separated so this isn't just a bare hash of the label) and
+    /// passes it to [`VsaOps::from_seed`].
+    pub fn atom_from_label(&self, label: &str) -> HyperVec {
+        let mut hasher = Sha256::new();
+        hasher.update(ATOM_LABEL_DOMAIN);
+        hasher.update(label.as_bytes());
+        let digest = hasher.finalize();
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest);
+        self.from_seed(seed)
+    }
+
+    /// Generate a deterministic hypervector from a 32-byte seed.
+    ///
+    /// Seeds a `ChaCha20Rng` (a CSPRNG, unlike `rand`'s default generators)
+    /// so the resulting vector is stable across Rust versions, processes,
+    /// and machines — the same seed always yields the same hypervector.
+    pub fn from_seed(&self, seed: [u8; 32]) -> HyperVec {
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        self.random(&mut rng)
+    }
+
     /// Bind two hypervectors (XOR for bipolar).
     ///
     /// Binding creates a representation that is dissimilar to both inputs
@@ -140,6 +177,57 @@ impl VsaOps {
         HyperVec::from_raw(out, v.dim(), v.encoding())
     }
 
+    /// Inverse of [`VsaOps::permute`]: cyclic shift in the opposite direction,
+    /// so `inverse_permute(permute(v, shift), shift) == v`.
+    pub fn inverse_permute(&self, v: &HyperVec, shift: usize) -> HyperVec {
+        // The kernel rotates over every bit of the packed bytes, padding included.
+        let total_bits = v.byte_len() * 8;
+        let inverse_shift = if total_bits == 0 {
+            0
+        } else {
+            (total_bits - shift % total_bits) % total_bits
+        };
+        self.permute(v, inverse_shift)
+    }
+
+    /// Encode an ordered sequence of hypervectors into a single hypervector,
+    /// by bundling `permute(items[i], i)` across positions.
+    ///
+    /// Positions can be recovered with [`VsaOps::position_of`].
+    pub fn encode_sequence(&self, items: &[&HyperVec]) -> VsaResult<HyperVec> {
+        let permuted: Vec<HyperVec> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| self.permute(item, i))
+            .collect();
+        let refs: Vec<&HyperVec> = permuted.iter().collect();
+        self.bundle(&refs)
+    }
+
+    /// Find the position of `item` within a hypervector produced by
+    /// [`VsaOps::encode_sequence`], trying every position up to `max_len`.
+    ///
+    /// For each candidate position `i`, `inverse_permute(seq, i)` undoes that
+    /// position's permutation, and the result is compared against `item` via
+    /// [`VsaOps::similarity`]. Returns the position with the highest
+    /// similarity, as long as it clears the uncorrelated baseline of 0.5 by a
+    /// comfortable margin; returns `None` if no position does (or if `seq`
+    /// and `item` aren't dimension/encoding-compatible).
+    pub fn position_of(&self, seq: &HyperVec, item: &HyperVec, max_len: usize) -> Option<usize> {
+        const BASELINE_MARGIN: f32 = 0.05;
+
+        let mut best: Option<(usize, f32)> = None;
+        for i in 0..max_len {
+            let candidate = self.inverse_permute(seq, i);
+            let sim = self.similarity(&candidate, item).ok()?;
+            if best.map_or(true, |(_, best_sim)| sim > best_sim) {
+                best = Some((i, sim));
+            }
+        }
+        best.filter(|(_, sim)| *sim > 0.5 + BASELINE_MARGIN)
+            .map(|(i, _)| i)
+    }
+
     /// Compute similarity between two hypervectors.
     ///
     /// For bipolar encoding, returns normalized Hamming similarity in `[0.0, 1.0]`
@@ -166,6 +254,112 @@ impl VsaOps {
             .collect();
         Ok(self.kernel.cosine_similarity_i8(&a_i8, &b_i8))
     }
+
+    /// Test whether `candidate` is plausibly a member of `bundle`, with a
+    /// tunable false-positive rate.
+    ///
+    /// Grounded in the known distribution: for `D`-dimensional bipolar
+    /// vectors, an unrelated (non-member) vector's normalized Hamming
+    /// similarity to `bundle` is approximately Normal with mean 0.5 and
+    /// variance `1/(4D)`, so this compares `similarity` against the decision
+    /// threshold `0.5 + z(false_positive_rate) * 0.5 / sqrt(D)`. A smaller
+    /// `false_positive_rate` raises the threshold, accepting fewer
+    /// non-members at the cost of also rejecting some true members.
+    pub fn is_member(
+        &self,
+        bundle: &HyperVec,
+        candidate: &HyperVec,
+        false_positive_rate: f32,
+    ) -> VsaResult<bool> {
+        let sim = self.similarity(bundle, candidate)?;
+        Ok(sim >= self.membership_threshold(false_positive_rate))
+    }
+
+    /// The largest bundle size whose expected member similarity still clears
+    /// [`VsaOps::is_member`]'s decision threshold at `false_positive_rate`,
+    /// capped at `max_items`.
+    ///
+    /// A bundled member's expected similarity decays roughly like
+    /// `0.5 + 0.5 / sqrt(n)` as the bundle grows to `n` items — this walks
+    /// `n` upward until that expectation drops below the threshold.
+    pub fn capacity(&self, max_items: usize, false_positive_rate: f32) -> usize {
+        let threshold = self.membership_threshold(false_positive_rate);
+        let mut best = 0usize;
+        for n in 1..=max_items {
+            let expected_member_similarity = 0.5 + 0.5 / (n as f32).sqrt();
+            if expected_member_similarity < threshold {
+                break;
+            }
+            best = n;
+        }
+        best
+    }
+
+    /// The similarity threshold above which a vector is treated as a member,
+    /// for the given false-positive rate. See [`VsaOps::is_member`].
+    fn membership_threshold(&self, false_positive_rate: f32) -> f32 {
+        let d = self.dim.0 as f32;
+        0.5 + normal_quantile(1.0 - false_positive_rate) * 0.5 / d.sqrt()
+    }
+}
+
+/// Approximate the standard normal quantile function (inverse CDF): the `z`
+/// such that `P(Z <= z) = p` for a standard normal `Z`.
+///
+/// Uses Peter Acklam's rational approximation, accurate to within about
+/// `1.15e-9` for `p` in `(0, 1)` — far more precision than a false-positive
+/// rate ever needs.
+fn normal_quantile(p: f32) -> f32 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01,
+        2.209460984245205e+02,
+        -2.759285104469687e+02,
+        1.383577518672690e+02,
+        -3.066479806614716e+01,
+        2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01,
+        1.615858368580409e+02,
+        -1.556989798598866e+02,
+        6.680131188771972e+01,
+        -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838e+00,
+        -2.549732539343734e+00,
+        4.374664141464968e+00,
+        2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+
+    let p = (p as f64).clamp(1e-12, 1.0 - 1e-12);
+    let p_low = 0.02425;
+    let p_high = 1.0 - p_low;
+
+    let z = if p < p_low {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= p_high {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        let q = (-2.0 * (1.0 - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    z as f32
 }
 
 impl std::fmt::Debug for VsaOps {
@@ -178,6 +372,83 @@ impl std::fmt::Debug for VsaOps {
     }
 }
 
+/// Streaming bundle accumulator: superpose hypervectors one at a time,
+/// with per-addition weights and optional exponential decay, instead of
+/// [`VsaOps::bundle`]'s all-at-once `&[&HyperVec]`.
+///
+/// This lets working/episodic memory maintain a running context vector
+/// across a session — observations can be weighted so higher-confidence
+/// evidence dominates, and [`BundleAccumulator::decay`] biases the final
+/// bundle toward whatever was added most recently.
+#[derive(Debug, Clone)]
+pub struct BundleAccumulator {
+    counters: Vec<f32>,
+    dim: Dimension,
+    encoding: Encoding,
+    has_data: bool,
+}
+
+impl BundleAccumulator {
+    /// Create an empty accumulator for the given dimension and encoding.
+    pub fn new(dim: Dimension, encoding: Encoding) -> Self {
+        Self {
+            counters: vec![0.0f32; dim.0],
+            dim,
+            encoding,
+            has_data: false,
+        }
+    }
+
+    /// Add `v` with weight 1.0. See [`BundleAccumulator::add_weighted`].
+    pub fn add(&mut self, v: &HyperVec) {
+        self.add_weighted(v, 1.0);
+    }
+
+    /// Add `v` scaled by `w`: each component's counter moves by `±w` instead
+    /// of `±1`, so a higher weight makes `v`'s bits dominate the eventual
+    /// bundle more strongly.
+    ///
+    /// Counters are kept as `f32` and only rounded in [`Self::finalize`], so
+    /// fractional weights below 0.5 still accumulate across repeated calls
+    /// instead of being dropped to zero on each one.
+    pub fn add_weighted(&mut self, v: &HyperVec, w: f32) {
+        for i in 0..self.dim.0 {
+            let delta = if v.get_bit(i) { w } else { -w };
+            self.counters[i] += delta;
+        }
+        self.has_data = true;
+    }
+
+    /// Multiply every counter by `factor`, ahead of the next `add`/`add_weighted`.
+    /// A `factor` below 1.0 fades earlier evidence in favor of what's added next.
+    ///
+    /// Applied in `f32` (not rounded back to an integer counter), so a small
+    /// per-step decay actually compounds into fading evidence over many
+    /// calls instead of rounding back to a no-op each time.
+    pub fn decay(&mut self, factor: f32) {
+        for c in &mut self.counters {
+            *c *= factor;
+        }
+    }
+
+    /// Threshold the accumulated counters into a hypervector, exactly like
+    /// [`VsaOps::bundle`]: positive → 1, zero → parity tie-break.
+    ///
+    /// Errors with [`VsaError::EmptyBundle`] if nothing has been added yet.
+    pub fn finalize(&self) -> VsaResult<HyperVec> {
+        if !self.has_data {
+            return Err(VsaError::EmptyBundle);
+        }
+
+        let mut result = HyperVec::zero(self.dim, self.encoding);
+        for i in 0..self.dim.0 {
+            let val = self.counters[i] > 0.0 || (self.counters[i] == 0.0 && i % 2 == 0);
+            result.set_bit(i, val);
+        }
+        Ok(result)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,6 +550,45 @@ mod tests {
         assert!(sim > 0.4 && sim < 0.6, "sim={sim}");
     }
 
+    #[test]
+    fn inverse_permute_is_exact_inverse() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        for shift in [0, 1, 7, 500, 1_000, 1_999] {
+            let permuted = ops.permute(&a, shift);
+            let restored = ops.inverse_permute(&permuted, shift);
+            assert_eq!(restored, a, "round-trip failed for shift={shift}");
+        }
+    }
+
+    #[test]
+    fn encode_sequence_round_trips_through_position_of() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+        let c = ops.random(&mut rng);
+
+        let seq = ops.encode_sequence(&[&a, &b, &c]).unwrap();
+
+        assert_eq!(ops.position_of(&seq, &a, 5), Some(0));
+        assert_eq!(ops.position_of(&seq, &b, 5), Some(1));
+        assert_eq!(ops.position_of(&seq, &c, 5), Some(2));
+    }
+
+    #[test]
+    fn position_of_unknown_item_is_none() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+        let stranger = ops.random(&mut rng);
+
+        let seq = ops.encode_sequence(&[&a, &b]).unwrap();
+        assert_eq!(ops.position_of(&seq, &stranger, 5), None);
+    }
+
     #[test]
     fn dimension_mismatch_detected() {
         let ops = test_ops();
@@ -288,6 +598,102 @@ mod tests {
         assert!(matches!(result, Err(VsaError::DimensionMismatch { .. })));
     }
 
+    #[test]
+    fn atom_from_label_is_deterministic() {
+        let ops = test_ops();
+        let a = ops.atom_from_label("sun");
+        let b = ops.atom_from_label("sun");
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn atom_from_label_differs_across_labels() {
+        let ops = test_ops();
+        let sun = ops.atom_from_label("sun");
+        let star = ops.atom_from_label("star");
+        let sim = ops.similarity(&sun, &star).unwrap();
+        assert!(sim > 0.4 && sim < 0.6, "sim={sim}");
+    }
+
+    #[test]
+    fn from_seed_is_deterministic() {
+        let ops = test_ops();
+        let seed = [7u8; 32];
+        let a = ops.from_seed(seed);
+        let b = ops.from_seed(seed);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn from_seed_differs_across_seeds() {
+        let ops = test_ops();
+        let a = ops.from_seed([1u8; 32]);
+        let b = ops.from_seed([2u8; 32]);
+        let sim = ops.similarity(&a, &b).unwrap();
+        assert!(sim > 0.4 && sim < 0.6, "sim={sim}");
+    }
+
+    #[test]
+    fn bundle_accumulator_empty_is_error() {
+        let acc = BundleAccumulator::new(Dimension::TEST, Encoding::Bipolar);
+        assert!(matches!(acc.finalize(), Err(VsaError::EmptyBundle)));
+    }
+
+    #[test]
+    fn bundle_accumulator_matches_bundle() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+        let c = ops.random(&mut rng);
+
+        let bundled = ops.bundle(&[&a, &b, &c]).unwrap();
+
+        let mut acc = BundleAccumulator::new(Dimension::TEST, Encoding::Bipolar);
+        acc.add(&a);
+        acc.add(&b);
+        acc.add(&c);
+        let streamed = acc.finalize().unwrap();
+
+        assert_eq!(bundled, streamed);
+    }
+
+    #[test]
+    fn bundle_accumulator_weight_dominates() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+
+        let mut acc = BundleAccumulator::new(Dimension::TEST, Encoding::Bipolar);
+        acc.add_weighted(&a, 10.0);
+        acc.add_weighted(&b, 1.0);
+        let result = acc.finalize().unwrap();
+
+        let sim_a = ops.similarity(&result, &a).unwrap();
+        assert!(sim_a > 0.9, "heavily-weighted input should dominate: {sim_a}");
+    }
+
+    #[test]
+    fn bundle_accumulator_decay_fades_old_evidence() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let old = ops.random(&mut rng);
+        let recent = ops.random(&mut rng);
+
+        let mut acc = BundleAccumulator::new(Dimension::TEST, Encoding::Bipolar);
+        acc.add(&old);
+        acc.decay(0.0);
+        acc.add(&recent);
+        let result = acc.finalize().unwrap();
+
+        let sim_recent = ops.similarity(&result, &recent).unwrap();
+        assert!(
+            (sim_recent - 1.0).abs() < 0.001,
+            "fully decayed old evidence should leave only the recent vector: {sim_recent}"
+        );
+    }
+
     #[test]
     fn cosine_similarity_identical() {
         let ops = test_ops();
@@ -296,4 +702,57 @@ mod tests {
         let sim = ops.cosine_similarity(&a, &a).unwrap();
         assert!((sim - 1.0).abs() < 0.001, "cosine self-sim={sim}");
     }
+
+    #[test]
+    fn normal_quantile_matches_known_values() {
+        assert!((normal_quantile(0.5) - 0.0).abs() < 1e-3);
+        assert!((normal_quantile(0.975) - 1.959_96).abs() < 1e-3);
+        assert!((normal_quantile(0.025) - (-1.959_96)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn is_member_accepts_bundle_members() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+        let c = ops.random(&mut rng);
+        let bundled = ops.bundle(&[&a, &b, &c]).unwrap();
+
+        assert!(ops.is_member(&bundled, &a, 0.01).unwrap());
+        assert!(ops.is_member(&bundled, &b, 0.01).unwrap());
+        assert!(ops.is_member(&bundled, &c, 0.01).unwrap());
+    }
+
+    #[test]
+    fn is_member_rejects_unrelated_vector() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let a = ops.random(&mut rng);
+        let b = ops.random(&mut rng);
+        let c = ops.random(&mut rng);
+        let bundled = ops.bundle(&[&a, &b, &c]).unwrap();
+
+        let stranger = ops.random(&mut rng);
+        assert!(!ops.is_member(&bundled, &stranger, 0.01).unwrap());
+    }
+
+    #[test]
+    fn capacity_shrinks_as_false_positive_rate_tightens() {
+        let ops = test_ops();
+        let loose = ops.capacity(1_000, 0.10);
+        let strict = ops.capacity(1_000, 0.001);
+        assert!(
+            strict <= loose,
+            "a stricter false-positive budget should not allow more items: strict={strict}, loose={loose}"
+        );
+        assert!(loose > 0, "a generous false-positive budget should allow at least one item");
+    }
+
+    #[test]
+    fn capacity_is_capped_by_max_items() {
+        let ops = test_ops();
+        assert!(ops.capacity(2, 0.10) <= 2);
+        assert_eq!(ops.capacity(0, 0.10), 0);
+    }
 }
@@ -0,0 +1,458 @@
+//! Block-compressed, random-access persistence for [`ItemMemory`].
+//!
+//! Mirrors how disc-image tools unify every archive format behind one block
+//! reader with pluggable codecs: vectors are grouped into fixed-count
+//! blocks, each block is compressed independently, and a trailing index maps
+//! `SymbolId` to `(block, slot)`. [`CompressedItemMemory`] decompresses a
+//! block on demand into a small LRU cache, so fetching one vector never
+//! requires inflating the whole store.
+//!
+//! File layout:
+//! ```text
+//! [header][block 0][block 1]...[block n-1][index][footer: index_offset: u64]
+//! ```
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::error::VsaError;
+use crate::symbol::SymbolId;
+
+use super::item_memory::ItemMemory;
+use super::ops::VsaResult;
+use super::{Dimension, Encoding, HyperVec};
+
+const MAGIC: &[u8; 8] = b"AKHIMEM\0";
+const VERSION: u32 = 1;
+const HEADER_SIZE: usize = 8 + 4 + 4 + 1 + 1 + 4 + 4; // magic+version+dim+encoding+codec+block_size+num_vectors
+
+/// Default number of decompressed blocks kept resident in
+/// [`CompressedItemMemory`]'s LRU cache.
+const DEFAULT_CACHE_BLOCKS: usize = 16;
+
+/// Compression codec for a saved item-memory file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// No compression — vectors are stored raw.
+    None,
+    /// Zstandard compression (fast, good ratio).
+    Zstd,
+    /// LZMA compression (slower, best ratio).
+    Lzma,
+}
+
+impl Codec {
+    fn to_byte(self) -> u8 {
+        match self {
+            Codec::None => 0,
+            Codec::Zstd => 1,
+            Codec::Lzma => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> VsaResult<Self> {
+        match byte {
+            0 => Ok(Codec::None),
+            1 => Ok(Codec::Zstd),
+            2 => Ok(Codec::Lzma),
+            other => Err(VsaError::CorruptFile {
+                message: format!("unknown codec id {other}"),
+            }),
+        }
+    }
+
+    fn compress(self, data: &[u8]) -> VsaResult<Vec<u8>> {
+        match self {
+            Codec::None => Ok(data.to_vec()),
+            Codec::Zstd => {
+                zstd::stream::encode_all(data, 0).map_err(|e| VsaError::Io { source: e })
+            }
+            Codec::Lzma => {
+                let mut out = Vec::new();
+                let mut encoder = xz2::write::XzEncoder::new(&mut out, 6);
+                encoder
+                    .write_all(data)
+                    .map_err(|e| VsaError::Io { source: e })?;
+                encoder.finish().map_err(|e| VsaError::Io { source: e })?;
+                Ok(out)
+            }
+        }
+    }
+
+    fn decompress(self, data: &[u8]) -> VsaResult<Vec<u8>> {
+        match self {
+            Codec::None => Ok(data.to_vec()),
+            Codec::Zstd => {
+                zstd::stream::decode_all(data).map_err(|e| VsaError::Io { source: e })
+            }
+            Codec::Lzma => {
+                let mut out = Vec::new();
+                xz2::read::XzDecoder::new(data)
+                    .read_to_end(&mut out)
+                    .map_err(|e| VsaError::Io { source: e })?;
+                Ok(out)
+            }
+        }
+    }
+}
+
+/// Header recorded at the start of a compressed item-memory file.
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    dim: Dimension,
+    encoding: Encoding,
+    codec: Codec,
+    block_size: u32,
+    num_vectors: u32,
+}
+
+fn encoding_to_byte(encoding: Encoding) -> u8 {
+    match encoding {
+        Encoding::Bipolar => 0,
+    }
+}
+
+fn encoding_from_byte(byte: u8) -> VsaResult<Encoding> {
+    match byte {
+        0 => Ok(Encoding::Bipolar),
+        other => Err(VsaError::CorruptFile {
+            message: format!("unknown encoding id {other}"),
+        }),
+    }
+}
+
+impl ItemMemory {
+    /// Save every vector currently held in this item memory to a
+    /// block-compressed, randomly-accessible file.
+    ///
+    /// Vectors are written in ascending `SymbolId` order, grouped into
+    /// blocks of `block_size` vectors each, with every block independently
+    /// compressed under `codec`. Reopen with [`CompressedItemMemory::open`].
+    pub fn save_compressed(&self, path: &Path, codec: Codec, block_size: usize) -> VsaResult<()> {
+        let block_size = block_size.max(1);
+        let mut entries: Vec<(SymbolId, HyperVec)> = self
+            .iter()
+            .map(|(symbol, vec)| (symbol, vec.clone()))
+            .collect();
+        entries.sort_by_key(|(symbol, _)| *symbol);
+
+        let mut file = File::create(path).map_err(|e| VsaError::Io { source: e })?;
+
+        file.write_all(MAGIC).map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&VERSION.to_le_bytes())
+            .map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&(self.dim().0 as u32).to_le_bytes())
+            .map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&[encoding_to_byte(self.encoding())])
+            .map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&[codec.to_byte()])
+            .map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&(block_size as u32).to_le_bytes())
+            .map_err(|e| VsaError::Io { source: e })?;
+        file.write_all(&(entries.len() as u32).to_le_bytes())
+            .map_err(|e| VsaError::Io { source: e })?;
+
+        for chunk in entries.chunks(block_size) {
+            let mut raw = Vec::with_capacity(chunk.len() * self.dim().binary_byte_len());
+            for (_, vec) in chunk {
+                raw.extend_from_slice(vec.data());
+            }
+            let compressed = codec.compress(&raw)?;
+            file.write_all(&(compressed.len() as u32).to_le_bytes())
+                .map_err(|e| VsaError::Io { source: e })?;
+            file.write_all(&compressed)
+                .map_err(|e| VsaError::Io { source: e })?;
+        }
+
+        let index_offset = file
+            .stream_position()
+            .map_err(|e| VsaError::Io { source: e })?;
+        for (symbol, _) in &entries {
+            file.write_all(&symbol.get().to_le_bytes())
+                .map_err(|e| VsaError::Io { source: e })?;
+        }
+        file.write_all(&index_offset.to_le_bytes())
+            .map_err(|e| VsaError::Io { source: e })?;
+
+        Ok(())
+    }
+}
+
+/// A random-access reader over a file written by
+/// [`ItemMemory::save_compressed`].
+///
+/// Unlike reopening into a full [`ItemMemory`] (which would decompress and
+/// re-insert every vector up front), this keeps only the id index resident
+/// and decompresses blocks on demand into a small LRU cache — the point of
+/// the format is to let a single vector be fetched without inflating the
+/// whole store.
+pub struct CompressedItemMemory {
+    file: Mutex<File>,
+    header: Header,
+    /// Sorted symbol ids, in the same order they were written — position
+    /// `i` lives in block `i / block_size` at slot `i % block_size`.
+    index: Vec<SymbolId>,
+    block_offsets: Vec<u64>,
+    cache: Mutex<LruCache<u32, Vec<HyperVec>>>,
+}
+
+impl CompressedItemMemory {
+    /// Open a compressed item-memory file for random-access reads.
+    pub fn open(path: &Path) -> VsaResult<Self> {
+        let mut file = File::open(path).map_err(|e| VsaError::Io { source: e })?;
+
+        let mut magic = [0u8; 8];
+        file.read_exact(&mut magic)
+            .map_err(|e| VsaError::Io { source: e })?;
+        if &magic != MAGIC {
+            return Err(VsaError::CorruptFile {
+                message: "bad magic — not an akh-medu item-memory file".into(),
+            });
+        }
+        let version = read_u32(&mut file)?;
+        if version != VERSION {
+            return Err(VsaError::CorruptFile {
+                message: format!("unsupported item-memory file version {version}"),
+            });
+        }
+        let dim = Dimension(read_u32(&mut file)? as usize);
+        let encoding = encoding_from_byte(read_u8(&mut file)?)?;
+        let codec = Codec::from_byte(read_u8(&mut file)?)?;
+        let block_size = read_u32(&mut file)?;
+        let num_vectors = read_u32(&mut file)?;
+
+        let header = Header {
+            dim,
+            encoding,
+            codec,
+            block_size,
+            num_vectors,
+        };
+
+        // The footer holds the absolute offset of the index.
+        file.seek(SeekFrom::End(-8))
+            .map_err(|e| VsaError::Io { source: e })?;
+        let index_offset = read_u64(&mut file)?;
+
+        file.seek(SeekFrom::Start(index_offset))
+            .map_err(|e| VsaError::Io { source: e })?;
+        let mut index = Vec::with_capacity(num_vectors as usize);
+        for _ in 0..num_vectors {
+            let raw_id = read_u64(&mut file)?;
+            let symbol = SymbolId::new(raw_id).ok_or_else(|| VsaError::CorruptFile {
+                message: "index contains a zero SymbolId".into(),
+            })?;
+            index.push(symbol);
+        }
+
+        // Re-scan the blocks to record each one's starting file offset, so a
+        // cache miss can seek straight to it.
+        let mut block_offsets = Vec::new();
+        file.seek(SeekFrom::Start(HEADER_SIZE as u64))
+            .map_err(|e| VsaError::Io { source: e })?;
+        let mut pos = HEADER_SIZE as u64;
+        while pos < index_offset {
+            block_offsets.push(pos);
+            let compressed_len = read_u32(&mut file)? as u64;
+            pos += 4 + compressed_len;
+            file.seek(SeekFrom::Start(pos))
+                .map_err(|e| VsaError::Io { source: e })?;
+        }
+
+        Ok(Self {
+            file: Mutex::new(file),
+            header,
+            index,
+            block_offsets,
+            cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(DEFAULT_CACHE_BLOCKS).expect("nonzero constant"),
+            )),
+        })
+    }
+
+    /// The dimension vectors in this file were stored with.
+    pub fn dim(&self) -> Dimension {
+        self.header.dim
+    }
+
+    /// The encoding vectors in this file were stored with.
+    pub fn encoding(&self) -> Encoding {
+        self.header.encoding
+    }
+
+    /// Number of vectors in this file.
+    pub fn len(&self) -> usize {
+        self.header.num_vectors as usize
+    }
+
+    /// Whether this file has no vectors.
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Fetch the hypervector for `symbol`, decompressing its containing
+    /// block (and caching it) if it isn't already cached.
+    pub fn get(&self, symbol: SymbolId) -> VsaResult<Option<HyperVec>> {
+        let Ok(position) = self.index.binary_search(&symbol) else {
+            return Ok(None);
+        };
+        let block_size = self.header.block_size as usize;
+        let block_idx = (position / block_size) as u32;
+        let slot = position % block_size;
+
+        let mut cache = self.cache.lock().expect("cache lock poisoned");
+        if let Some(block) = cache.get(&block_idx) {
+            return Ok(block.get(slot).cloned());
+        }
+        drop(cache);
+
+        let block = self.load_block(block_idx)?;
+        let vec = block.get(slot).cloned();
+        self.cache
+            .lock()
+            .expect("cache lock poisoned")
+            .put(block_idx, block);
+        Ok(vec)
+    }
+
+    fn load_block(&self, block_idx: u32) -> VsaResult<Vec<HyperVec>> {
+        let offset = self.block_offsets[block_idx as usize];
+        let mut file = self.file.lock().expect("file lock poisoned");
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| VsaError::Io { source: e })?;
+        let compressed_len = read_u32(&mut file)?;
+        let mut compressed = vec![0u8; compressed_len as usize];
+        file.read_exact(&mut compressed)
+            .map_err(|e| VsaError::Io { source: e })?;
+        drop(file);
+
+        let raw = self.header.codec.decompress(&compressed)?;
+        let vec_len = self.header.dim.binary_byte_len();
+        Ok(raw
+            .chunks(vec_len)
+            .map(|chunk| HyperVec::from_raw(chunk.to_vec(), self.header.dim, self.header.encoding))
+            .collect())
+    }
+}
+
+fn read_u8(file: &mut File) -> VsaResult<u8> {
+    let mut buf = [0u8; 1];
+    file.read_exact(&mut buf).map_err(|e| VsaError::Io { source: e })?;
+    Ok(buf[0])
+}
+
+fn read_u32(file: &mut File) -> VsaResult<u32> {
+    let mut buf = [0u8; 4];
+    file.read_exact(&mut buf).map_err(|e| VsaError::Io { source: e })?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(file: &mut File) -> VsaResult<u64> {
+    let mut buf = [0u8; 8];
+    file.read_exact(&mut buf).map_err(|e| VsaError::Io { source: e })?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simd;
+    use crate::vsa::ops::VsaOps;
+    use tempfile::TempDir;
+
+    fn test_ops() -> VsaOps {
+        VsaOps::new(simd::best_kernel(), Dimension::TEST, Encoding::Bipolar)
+    }
+
+    fn populated_memory(ops: &VsaOps, count: u64) -> ItemMemory {
+        let mem = ItemMemory::new(Dimension::TEST, Encoding::Bipolar, 1000);
+        for i in 1..=count {
+            mem.get_or_create(ops, SymbolId::new(i).unwrap());
+        }
+        mem
+    }
+
+    #[test]
+    fn round_trips_uncompressed() {
+        let ops = test_ops();
+        let mem = populated_memory(&ops, 10);
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mem.akhi");
+
+        mem.save_compressed(&path, Codec::None, 4).unwrap();
+        let reader = CompressedItemMemory::open(&path).unwrap();
+
+        assert_eq!(reader.len(), 10);
+        for i in 1..=10u64 {
+            let sym = SymbolId::new(i).unwrap();
+            assert_eq!(reader.get(sym).unwrap(), mem.get(sym));
+        }
+    }
+
+    #[test]
+    fn round_trips_zstd() {
+        let ops = test_ops();
+        let mem = populated_memory(&ops, 25);
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mem.akhi");
+
+        mem.save_compressed(&path, Codec::Zstd, 6).unwrap();
+        let reader = CompressedItemMemory::open(&path).unwrap();
+
+        for i in 1..=25u64 {
+            let sym = SymbolId::new(i).unwrap();
+            assert_eq!(reader.get(sym).unwrap(), mem.get(sym));
+        }
+    }
+
+    #[test]
+    fn round_trips_lzma() {
+        let ops = test_ops();
+        let mem = populated_memory(&ops, 25);
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mem.akhi");
+
+        mem.save_compressed(&path, Codec::Lzma, 6).unwrap();
+        let reader = CompressedItemMemory::open(&path).unwrap();
+
+        for i in 1..=25u64 {
+            let sym = SymbolId::new(i).unwrap();
+            assert_eq!(reader.get(sym).unwrap(), mem.get(sym));
+        }
+    }
+
+    #[test]
+    fn missing_symbol_returns_none() {
+        let ops = test_ops();
+        let mem = populated_memory(&ops, 5);
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mem.akhi");
+
+        mem.save_compressed(&path, Codec::Zstd, 2).unwrap();
+        let reader = CompressedItemMemory::open(&path).unwrap();
+
+        assert_eq!(reader.get(SymbolId::new(999).unwrap()).unwrap(), None);
+    }
+
+    #[test]
+    fn cache_eviction_still_reads_correctly() {
+        let ops = test_ops();
+        let mem = populated_memory(&ops, 200);
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("mem.akhi");
+
+        // Small blocks force many blocks, well beyond the cache capacity.
+        mem.save_compressed(&path, Codec::Zstd, 3).unwrap();
+        let reader = CompressedItemMemory::open(&path).unwrap();
+
+        for i in 1..=200u64 {
+            let sym = SymbolId::new(i).unwrap();
+            assert_eq!(reader.get(sym).unwrap(), mem.get(sym));
+        }
+    }
+}
@@ -0,0 +1,269 @@
+//! Resonator-network factorization: recover the factors of a bound
+//! composite hypervector against known codebooks.
+//!
+//! `VsaOps::bind` composes factors into a single hypervector that is, by
+//! design, dissimilar to every input — binding is one-way unless you already
+//! know a factor to unbind by. A resonator network (Frady, Kent, Olshausen &
+//! Sommer) recovers all factors at once from the composite alone, given one
+//! codebook of candidates per factor, by alternating between unbinding
+//! current estimates out of the composite and cleaning the result up to the
+//! nearest codebook entry.
+
+use super::HyperVec;
+use super::ops::{VsaOps, VsaResult};
+use crate::error::VsaError;
+
+/// Default iteration cap for [`resonate`], generous enough for the
+/// low-dozens-of-factors compositions this crate expects to decode.
+pub const DEFAULT_MAX_ITERATIONS: usize = 100;
+
+/// Outcome of running the resonator network to convergence (or giving up).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResonatorResult {
+    /// Winning codebook index for each factor, in the same order as the
+    /// `codebooks` slice passed to [`resonate`].
+    pub factors: Vec<usize>,
+    /// Whether the estimates settled on a stable assignment before
+    /// `max_iterations` (or a detected oscillation) cut the search short.
+    pub converged: bool,
+    /// Number of Jacobi update rounds actually run.
+    pub iterations: usize,
+}
+
+/// Factor `composite` against `codebooks` using a resonator network.
+///
+/// `composite` is expected to be (approximately) the XOR-bind of one entry
+/// from each codebook, in order — `codebooks[0][i0] ⊛ codebooks[1][i1] ⊛ …`.
+/// Returns the winning `i0, i1, …` indices.
+///
+/// # Algorithm
+///
+/// Each estimate `ĝi` starts at the bundle (superposition) of its codebook,
+/// an uninformative starting point equally similar to every candidate. Every
+/// round, for each factor `i` simultaneously (Jacobi-style: every update
+/// reads only the *previous* round's estimates, never one computed earlier
+/// in the same round):
+///
+/// 1. Unbind the composite by every other current estimate: since XOR-bind
+///    is its own inverse and associative/commutative, chaining
+///    `composite ⊛ ĝ0 ⊛ … ⊛ ĝ(i-1) ⊛ ĝ(i+1) ⊛ …` (skipping `ĝi`) leaves an
+///    estimate of factor `i` alone.
+/// 2. Clean that estimate up to the nearest codebook entry — the one with
+///    minimum Hamming distance, equivalently maximum [`VsaOps::similarity`]
+///    — breaking ties by the lowest index.
+///
+/// All `k` updates are applied together at the end of the round. The search
+/// stops when a round leaves every factor's winning index unchanged
+/// (converged), when the new assignment repeats one already seen earlier
+/// (an oscillation that will never settle), or when `max_iterations` is
+/// reached — whichever comes first.
+///
+/// # Errors
+///
+/// Returns [`VsaError::EmptyBundle`] if `codebooks` is empty or any
+/// individual codebook is empty, and [`VsaError::DimensionMismatch`] if
+/// `composite` or any codebook entry doesn't share the others' dimension.
+pub fn resonate(
+    ops: &VsaOps,
+    composite: &HyperVec,
+    codebooks: &[&[HyperVec]],
+    max_iterations: usize,
+) -> VsaResult<ResonatorResult> {
+    if codebooks.is_empty() {
+        return Err(VsaError::EmptyBundle);
+    }
+    for book in codebooks {
+        if book.is_empty() {
+            return Err(VsaError::EmptyBundle);
+        }
+        for entry in *book {
+            if entry.dim() != composite.dim() {
+                return Err(VsaError::DimensionMismatch {
+                    expected: composite.dim().0,
+                    actual: entry.dim().0,
+                });
+            }
+            if entry.encoding() != composite.encoding() {
+                return Err(VsaError::UnsupportedEncoding {
+                    encoding: format!("mixed encodings: {} and {}", composite.encoding(), entry.encoding()),
+                });
+            }
+        }
+    }
+
+    let k = codebooks.len();
+    let mut estimates = Vec::with_capacity(k);
+    for book in codebooks {
+        let refs: Vec<&HyperVec> = book.iter().collect();
+        estimates.push(ops.bundle(&refs)?);
+    }
+
+    let mut indices: Option<Vec<usize>> = None;
+    let mut history: Vec<Vec<usize>> = Vec::new();
+    let mut iterations = 0usize;
+
+    while iterations < max_iterations {
+        let mut next_indices = Vec::with_capacity(k);
+        let mut next_estimates = Vec::with_capacity(k);
+
+        for (i, book) in codebooks.iter().enumerate() {
+            let mut unbound = composite.clone();
+            for (j, estimate) in estimates.iter().enumerate() {
+                if j != i {
+                    unbound = ops.bind(&unbound, estimate)?;
+                }
+            }
+            let winner = nearest_index(ops, &unbound, book)?;
+            next_indices.push(winner);
+            next_estimates.push(book[winner].clone());
+        }
+
+        iterations += 1;
+
+        if indices.as_ref() == Some(&next_indices) {
+            return Ok(ResonatorResult {
+                factors: next_indices,
+                converged: true,
+                iterations,
+            });
+        }
+        if history.contains(&next_indices) {
+            return Ok(ResonatorResult {
+                factors: next_indices,
+                converged: false,
+                iterations,
+            });
+        }
+
+        history.push(next_indices.clone());
+        indices = Some(next_indices);
+        estimates = next_estimates;
+    }
+
+    Ok(ResonatorResult {
+        factors: indices.unwrap_or_else(|| vec![0; k]),
+        converged: false,
+        iterations,
+    })
+}
+
+/// Index of `book`'s entry closest to `query` by Hamming distance
+/// (equivalently, highest [`VsaOps::similarity`]); ties keep the lowest
+/// index, since `>` only replaces the incumbent on a strict improvement.
+fn nearest_index(ops: &VsaOps, query: &HyperVec, book: &[HyperVec]) -> VsaResult<usize> {
+    let mut best_index = 0usize;
+    let mut best_similarity = f32::NEG_INFINITY;
+    for (i, candidate) in book.iter().enumerate() {
+        let similarity = ops.similarity(query, candidate)?;
+        if similarity > best_similarity {
+            best_similarity = similarity;
+            best_index = i;
+        }
+    }
+    Ok(best_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simd;
+    use crate::vsa::{Dimension, Encoding};
+    use rand::SeedableRng;
+
+    fn test_ops() -> VsaOps {
+        VsaOps::new(simd::best_kernel(), Dimension::TEST, Encoding::Bipolar)
+    }
+
+    fn seeded_rng() -> rand::rngs::StdRng {
+        rand::rngs::StdRng::seed_from_u64(42)
+    }
+
+    fn random_codebook(ops: &VsaOps, rng: &mut impl rand::Rng, n: usize) -> Vec<HyperVec> {
+        (0..n).map(|_| ops.random(rng)).collect()
+    }
+
+    #[test]
+    fn resonate_recovers_two_factors() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let book_a = random_codebook(&ops, &mut rng, 6);
+        let book_b = random_codebook(&ops, &mut rng, 6);
+
+        let composite = ops.bind(&book_a[2], &book_b[4]).unwrap();
+        let result = resonate(&ops, &composite, &[&book_a, &book_b], DEFAULT_MAX_ITERATIONS).unwrap();
+
+        assert!(result.converged);
+        assert_eq!(result.factors, vec![2, 4]);
+    }
+
+    #[test]
+    fn resonate_recovers_three_factors() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let book_a = random_codebook(&ops, &mut rng, 5);
+        let book_b = random_codebook(&ops, &mut rng, 5);
+        let book_c = random_codebook(&ops, &mut rng, 5);
+
+        let composite = ops
+            .bind(&ops.bind(&book_a[0], &book_b[3]).unwrap(), &book_c[1])
+            .unwrap();
+        let result = resonate(
+            &ops,
+            &composite,
+            &[&book_a, &book_b, &book_c],
+            DEFAULT_MAX_ITERATIONS,
+        )
+        .unwrap();
+
+        assert!(result.converged);
+        assert_eq!(result.factors, vec![0, 3, 1]);
+    }
+
+    #[test]
+    fn resonate_single_factor_is_trivial() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let book = random_codebook(&ops, &mut rng, 4);
+
+        let result = resonate(&ops, &book[3], &[&book], DEFAULT_MAX_ITERATIONS).unwrap();
+
+        assert!(result.converged);
+        assert_eq!(result.factors, vec![3]);
+        assert_eq!(result.iterations, 2);
+    }
+
+    #[test]
+    fn resonate_zero_max_iterations_does_not_converge() {
+        let ops = test_ops();
+        let mut rng = seeded_rng();
+        let book_a = random_codebook(&ops, &mut rng, 4);
+        let book_b = random_codebook(&ops, &mut rng, 4);
+        let composite = ops.bind(&book_a[1], &book_b[2]).unwrap();
+
+        let result = resonate(&ops, &composite, &[&book_a, &book_b], 0).unwrap();
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 0);
+    }
+
+    #[test]
+    fn resonate_rejects_empty_codebooks() {
+        let ops = test_ops();
+        let book_a: Vec<HyperVec> = Vec::new();
+        let composite = HyperVec::zero(Dimension::TEST, Encoding::Bipolar);
+        let result = resonate(&ops, &composite, &[&book_a], DEFAULT_MAX_ITERATIONS);
+        assert!(matches!(result, Err(VsaError::EmptyBundle)));
+
+        let result = resonate(&ops, &composite, &[], DEFAULT_MAX_ITERATIONS);
+        assert!(matches!(result, Err(VsaError::EmptyBundle)));
+    }
+
+    #[test]
+    fn resonate_rejects_dimension_mismatch() {
+        let ops = test_ops();
+        let composite = HyperVec::zero(Dimension::TEST, Encoding::Bipolar);
+        let book = vec![HyperVec::zero(Dimension(100), Encoding::Bipolar)];
+        let result = resonate(&ops, &composite, &[&book], DEFAULT_MAX_ITERATIONS);
+        assert!(matches!(result, Err(VsaError::DimensionMismatch { .. })));
+    }
+}